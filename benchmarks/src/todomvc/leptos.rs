@@ -110,15 +110,15 @@ pub fn TodoMVC(cx: Scope, todos: Todos) -> Element {
     provide_context(cx, set_todos);
 
     let (mode, set_mode) = create_signal(cx, Mode::All);
-    window_event_listener("hashchange", move |_| {
+    window_event_listener(cx, "hashchange", move |_| {
         let new_mode = location_hash().map(|hash| route(&hash)).unwrap_or_default();
         set_mode(new_mode);
     });
 
-    let add_todo = move |ev: web_sys::Event| {
+    let add_todo = move |ev: web_sys::KeyboardEvent| {
         let target = event_target::<HtmlInputElement>(&ev);
         ev.stop_propagation();
-        let key_code = ev.unchecked_ref::<web_sys::KeyboardEvent>().key_code();
+        let key_code = ev.key_code();
         if key_code == ENTER_KEY {
             let title = event_target_value(&ev);
             let title = title.trim();
@@ -260,7 +260,7 @@ pub fn Todo(cx: Scope, todo: Todo) -> Element {
                     prop:value={move || todo.title.get()}
                     on:focusout=move |ev| save(&event_target_value(&ev))
                     on:keyup={move |ev| {
-                        let key_code = ev.unchecked_ref::<web_sys::KeyboardEvent>().key_code();
+                        let key_code = ev.key_code();
                         if key_code == ENTER_KEY {
                             save(&event_target_value(&ev));
                         } else if key_code == ESCAPE_KEY {
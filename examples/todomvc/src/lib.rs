@@ -128,16 +128,16 @@ pub fn TodoMVC(cx: Scope) -> Element {
 
     // Handle the three filter modes: All, Active, and Completed
     let (mode, set_mode) = create_signal(cx, Mode::All);
-    window_event_listener("hashchange", move |_| {
+    window_event_listener(cx, "hashchange", move |_| {
         let new_mode = location_hash().map(|hash| route(&hash)).unwrap_or_default();
         set_mode(new_mode);
     });
 
     // Callback to add a todo on pressing the `Enter` key, if the field isn't empty
-    let add_todo = move |ev: web_sys::Event| {
+    let add_todo = move |ev: web_sys::KeyboardEvent| {
         let target = event_target::<HtmlInputElement>(&ev);
         ev.stop_propagation();
-        let key_code = ev.unchecked_ref::<web_sys::KeyboardEvent>().key_code();
+        let key_code = ev.key_code();
         if key_code == ENTER_KEY {
             let title = event_target_value(&ev);
             let title = title.trim();
@@ -259,8 +259,8 @@ pub fn Todo(cx: Scope, todo: Todo) -> Element {
     let (editing, set_editing) = create_signal(cx, false);
     let set_todos = use_context::<WriteSignal<Todos>>(cx).unwrap();
 
-    // this will be filled by _ref=input below
-    let input: Element;
+    // this will be filled by ref=input below
+    let input = create_node_ref::<HtmlInputElement>(cx);
 
     let save = move |value: &str| {
         let value = value.trim();
@@ -272,15 +272,11 @@ pub fn Todo(cx: Scope, todo: Todo) -> Element {
         set_editing(false);
     };
 
-    // the `input` variable above is filled by a ref, when the template is created
-    // so we create the template and store it in a variable here, so we can
-    // set up an effect using the `input` below
     let tpl = view! { cx,
         <li
             class="todo"
             class:editing={editing}
             class:completed={move || todo.completed.get()}
-            _ref=input
         >
             <div class="view">
                 <input
@@ -300,11 +296,12 @@ pub fn Todo(cx: Scope, todo: Todo) -> Element {
             {move || editing().then(|| view! { cx,
                 <input
                     class="edit"
+                    ref=input
                     class:hidden={move || !(editing)()}
                     prop:value={move || todo.title.get()}
                     on:focusout=move |ev| save(&event_target_value(&ev))
                     on:keyup={move |ev| {
-                        let key_code = ev.unchecked_ref::<web_sys::KeyboardEvent>().key_code();
+                        let key_code = ev.key_code();
                         if key_code == ENTER_KEY {
                             save(&event_target_value(&ev));
                         } else if key_code == ESCAPE_KEY {
@@ -318,11 +315,10 @@ pub fn Todo(cx: Scope, todo: Todo) -> Element {
     };
 
     // toggling to edit mode should focus the input
-    #[cfg(any(feature = "csr", feature = "hydrate"))]
     create_effect(cx, move |_| {
         if editing() {
-            if let Some(input) = input.dyn_ref::<HtmlInputElement>() {
-                input.focus();
+            if let Some(input) = input.get() {
+                _ = input.focus();
             }
         }
     });
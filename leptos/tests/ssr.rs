@@ -61,6 +61,696 @@ fn ssr_test_with_components() {
     });
 }
 
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_with_spread_attributes() {
+    use leptos_core as leptos;
+    use leptos_core::Prop;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, Scope};
+
+    #[component]
+    fn Button(cx: Scope, label: &'static str, attrs: SpreadAttributes) -> Element {
+        view! {
+            cx,
+            <button type="button" {..attrs}>{label}</button>
+        }
+    }
+
+    _ = create_scope(|cx| {
+        let extra = vec![("id", "save".into_attribute(cx))];
+        let rendered = view! {
+            cx,
+            <div>
+                <Button label="Save" aria-label="Save the file" data-disabled=false {..extra}/>
+            </div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><!--#--><button data-hk="0-2-0" type="button" aria-label="Save the file" id="save"><!--#-->Save<!--/--></button><!--/--></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_spread_attributes_are_escaped() {
+    use leptos_dom::*;
+    use leptos_macro::view;
+    use leptos_reactive::create_scope;
+
+    _ = create_scope(|cx| {
+        let attrs = vec![("title", r#"a "quoted" <b"#.into_attribute(cx))];
+        let rendered = view! {
+            cx,
+            <div {..attrs}></div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0" title="a &quot;quoted&quot; &lt;b"></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_portal_renders_nothing() {
+    use leptos_core as leptos;
+    use leptos_core::{Portal, PortalProps, Prop};
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::create_scope;
+
+    _ = create_scope(|cx| {
+        let rendered = view! {
+            cx,
+            <div>
+                <Portal>
+                    <div class="modal">"Hello from elsewhere"</div>
+                </Portal>
+                <Portal>
+                    "A toast"
+                </Portal>
+            </div>
+        };
+
+        assert_eq!(rendered, r#"<div data-hk="0-0"><!--#--><!--/--><!--#--><!--/--></div>"#);
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_portal_keeps_sibling_hydration_keys() {
+    use leptos_core as leptos;
+    use leptos_core::{Portal, PortalProps, Prop};
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, Scope};
+
+    // the portal's children use up the same keys the browser will give them,
+    // so the sibling rendered after it is hydrated from the right node
+    #[component]
+    fn Modal(cx: Scope) -> Element {
+        _ = Portal(
+            cx,
+            PortalProps::builder()
+                .children(Box::new(move || vec![view! { cx, <p>"In the portal"</p> }]))
+                .build(),
+        );
+        view! { cx, <p>"After the portal"</p> }
+    }
+
+    _ = create_scope(|cx| {
+        let rendered = view! {
+            cx,
+            <div>
+                <Modal/>
+            </div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><!--#--><p data-hk="0-2-1">After the portal</p><!--/--></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_control_flow() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+
+    _ = create_scope(|cx| {
+        let (count, set_count) = create_signal(cx, 5);
+        let rendered = view! {
+            cx,
+            <div>
+                <Show when={move || count() > 3} fallback={|cx| view! { cx, <p>"Small"</p> }}>
+                    {|cx: Scope| view! { cx, <p>"Big"</p> }}
+                </Show>
+                <Switch fallback={|cx| view! { cx, <p>"None"</p> }}>
+                    <Match when={move || count() > 10}>{|cx: Scope| view! { cx, <p>"Huge"</p> }}</Match>
+                    <Match when={move || count() > 1}>{|cx: Scope| view! { cx, <p>"Some"</p> }}</Match>
+                </Switch>
+            </div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><!--#--><p data-hk="0-2-0">Big</p><!--/--><!--#--><p data-hk="0-3-2">Some</p><!--/--></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_control_flow_text_fallbacks() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+
+    _ = create_scope(|cx| {
+        let (count, set_count) = create_signal(cx, 5);
+        let rendered = view! {
+            cx,
+            <div>
+                <Show when={move || count() > 10} fallback={|_| "Small"}>
+                    {|cx: Scope| view! { cx, <p>"Big"</p> }}
+                </Show>
+                <Switch fallback={move |_| format!("None of {}", count())}>
+                    <Match when={move || count() > 10}>{|cx: Scope| view! { cx, <p>"Huge"</p> }}</Match>
+                </Switch>
+            </div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><!--#-->Small<!--/--><!--#-->None of 5<!--/--></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn show_only_recreates_branch_when_condition_flips() {
+    use leptos_core::*;
+    use leptos_dom::Child;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+    use std::{cell::Cell, rc::Rc};
+
+    _ = create_scope(|cx| {
+        let (count, set_count) = create_signal(cx, 5);
+        let renders = Rc::new(Cell::new(0));
+        let show = Show(
+            cx,
+            ShowProps::builder()
+                .when(move || count() > 3)
+                .children(Box::new({
+                    let renders = Rc::clone(&renders);
+                    move || {
+                        let renders = Rc::clone(&renders);
+                        vec![move |_cx: Scope| {
+                            renders.set(renders.get() + 1);
+                            "Big".to_string()
+                        }]
+                    }
+                }))
+                .build(),
+        );
+
+        assert_eq!(show(), Child::Text("Big".into()));
+        set_count(6);
+        assert_eq!(show(), Child::Text("Big".into()));
+        assert_eq!(renders.get(), 1);
+
+        set_count(1);
+        assert_eq!(show(), Child::Null);
+        set_count(7);
+        assert_eq!(show(), Child::Text("Big".into()));
+        assert_eq!(renders.get(), 2);
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn dynamic_accepts_functions_and_closures() {
+    use leptos_core::*;
+    use leptos_dom::Child;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+
+    fn admin_panel(_cx: Scope) -> String {
+        "Admin".to_string()
+    }
+
+    fn user_panel(_cx: Scope) -> String {
+        "User".to_string()
+    }
+
+    _ = create_scope(|cx| {
+        let (is_admin, set_is_admin) = create_signal(cx, false);
+        let panel = Dynamic(
+            cx,
+            DynamicProps::builder()
+                .component(move || if is_admin() { admin_panel } else { user_panel })
+                .build(),
+        );
+        assert_eq!(panel(), Child::Text("User".into()));
+        set_is_admin(true);
+        assert_eq!(panel(), Child::Text("Admin".into()));
+
+        // a closure can capture what it needs to render
+        let (name, set_name) = create_signal(cx, "Ada".to_string());
+        let greeting = Dynamic(
+            cx,
+            DynamicProps::builder()
+                .component(move || {
+                    let name = name();
+                    move |_cx: Scope| format!("Hello, {name}")
+                })
+                .build(),
+        );
+        assert_eq!(greeting(), Child::Text("Hello, Ada".into()));
+        set_name("Grace".to_string());
+        assert_eq!(greeting(), Child::Text("Hello, Grace".into()));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn dynamic_keeps_the_same_component() {
+    use leptos_core::*;
+    use leptos_dom::Child;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+    use std::{cell::Cell, rc::Rc};
+
+    _ = create_scope(|cx| {
+        let runs = Rc::new(Cell::new(0));
+        let panel = DynamicComponent::new({
+            let runs = Rc::clone(&runs);
+            move |_cx: Scope| {
+                runs.set(runs.get() + 1);
+                "Panel".to_string()
+            }
+        });
+
+        let (count, set_count) = create_signal(cx, 0);
+        let view = Dynamic(
+            cx,
+            DynamicProps::builder()
+                .component(move || {
+                    count();
+                    panel.clone()
+                })
+                .build(),
+        );
+        assert_eq!(view(), Child::Text("Panel".into()));
+        assert_eq!(runs.get(), 1);
+
+        // `component` runs again, but returns the same component
+        set_count(1);
+        assert_eq!(view(), Child::Text("Panel".into()));
+        assert_eq!(runs.get(), 1);
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_for_with_fragments_and_text() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+
+    _ = create_scope(|cx| {
+        let (rows, set_rows) = create_signal(cx, vec![1, 2]);
+        let rendered = view! {
+            cx,
+            <div>
+                <tr>
+                    <For each={rows} key={|row| *row}>
+                        {|cx: Scope, row: &i32| {
+                            let row = *row;
+                            view! { cx, <><td>{row}</td><td>"Row"</td></> }
+                        }}
+                    </For>
+                </tr>
+                <p>
+                    <For each={rows} key={|row| *row}>
+                        {|_cx: Scope, row: &i32| format!("{row};")}
+                    </For>
+                </p>
+            </div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><tr><!--#--><td data-hk="0-2-0"><!--#-->1<!--/--></td><td data-hk="0-2-1">Row</td><td data-hk="0-2-2"><!--#-->2<!--/--></td><td data-hk="0-2-3">Row</td><!--/--></tr><p><!--#-->1;2;<!--/--></p></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_index() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, create_signal, ReadSignal, Scope};
+
+    _ = create_scope(|cx| {
+        let (values, set_values) = create_signal(cx, vec![1, 2, 3]);
+        let rendered = view! {
+            cx,
+            <ul>
+                <Index each={values}>
+                    {|cx: Scope, value: ReadSignal<i32>| view! { cx, <li>{move || value().to_string()}</li> }}
+                </Index>
+            </ul>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<ul data-hk="0-0"><!--#--><li data-hk="0-2-0"><!--#-->1<!--/--></li><li data-hk="0-2-1"><!--#-->2<!--/--></li><li data-hk="0-2-2"><!--#-->3<!--/--></li><!--/--></ul>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_virtual_list_renders_initial_window() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, Scope};
+
+    _ = create_scope(|cx| {
+        let rendered = view! {
+            cx,
+            <main>
+                <VirtualList each={|| (0..100).collect::<Vec<usize>>()} key={|n| *n} item_height=20.0 height=50.0 overscan=1>
+                    {|_cx: Scope, n: &usize| format!("Row {n}")}
+                </VirtualList>
+            </main>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<main data-hk="0-0"><!--#--><div data-hk="0-2-4" style="height: 50px; overflow-y: auto;"><div style="position: relative; height: 2000px;"><div style="position: absolute; top: 0px; left: 0; right: 0;"><div data-hk="0-2-0">Row 0</div><div data-hk="0-2-1">Row 1</div><div data-hk="0-2-2">Row 2</div><div data-hk="0-2-3">Row 3</div></div></div></div><!--/--></main>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_virtual_list_shorter_than_viewport() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, Scope};
+
+    _ = create_scope(|cx| {
+        let rendered = view! {
+            cx,
+            <main>
+                <VirtualList each={|| vec![7_usize, 8]} key={|n| *n} item_height=20.0 height=50.0>
+                    {|_cx: Scope, n: &usize| format!("Row {n}")}
+                </VirtualList>
+            </main>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<main data-hk="0-0"><!--#--><div data-hk="0-2-2" style="height: 50px; overflow-y: auto;"><div style="position: relative; height: 40px;"><div style="position: absolute; top: 0px; left: 0; right: 0;"><div data-hk="0-2-0">Row 7</div><div data-hk="0-2-1">Row 8</div></div></div></div><!--/--></main>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_virtual_list_shrinks() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, create_signal, Scope};
+    use std::cell::RefCell;
+
+    thread_local! {
+        static RENDERED_ROWS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    _ = create_scope(|cx| {
+        let (items, set_items) = create_signal(cx, (0..100).collect::<Vec<usize>>());
+        _ = view! {
+            cx,
+            <main>
+                <VirtualList each={move || items.get()} key={|n| *n} item_height=20.0 height=50.0 overscan=1>
+                    {|_cx: Scope, n: &usize| {
+                        RENDERED_ROWS.with(|rows| rows.borrow_mut().push(*n));
+                        format!("Row {n}")
+                    }}
+                </VirtualList>
+            </main>
+        };
+        assert_eq!(RENDERED_ROWS.with(|rows| rows.take()), vec![0, 1, 2, 3]);
+
+        // the rows are updated, rather than sliced with the window of the longer list
+        set_items.set(vec![100, 101]);
+        assert_eq!(RENDERED_ROWS.with(|rows| rows.take()), vec![100, 101]);
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_suspense() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, use_context, Scope, SuspenseContext};
+
+    // stands in for a resource that is still loading
+    #[component]
+    fn Pending(cx: Scope) -> Element {
+        use_context::<SuspenseContext>(cx).unwrap().increment();
+        view! { cx, <span>"Pending"</span> }
+    }
+
+    _ = create_scope(|cx| {
+        let rendered = view! {
+            cx,
+            <div>
+                <Suspense fallback={"Loading outer"}>
+                    <p>"One"</p>
+                    <p>"Two"</p>
+                    <div>
+                        <Suspense fallback={"Loading inner"}>
+                            <Pending/>
+                        </Suspense>
+                    </div>
+                </Suspense>
+            </div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><!--#--><p data-hk="0-2-0">One</p><p data-hk="0-2-1">Two</p><div data-hk="0-2-2"><!--#--><div data-hk="0-2-4" data-fragment-id="0-2-4-0f"><!--#-->Loading inner<!--/--></div><!--/--></div><!--/--></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_suspense_delayed_fallback() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, use_context, Scope, SuspenseContext};
+
+    #[component]
+    fn Pending(cx: Scope) -> Element {
+        use_context::<SuspenseContext>(cx).unwrap().increment();
+        view! { cx, <span>"Pending"</span> }
+    }
+
+    _ = create_scope(|cx| {
+        let rendered = view! {
+            cx,
+            <div>
+                <Suspense fallback={"Loading"} delay_ms=200 min_duration_ms=500 timeout_ms=5000>
+                    <Pending/>
+                </Suspense>
+            </div>
+        };
+
+        // the fallback is left out of the shell, and streamed later if it's still needed
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0"><!--#--><div data-hk="0-3" data-fragment-id="0-2-0f"><!--#--><template data-hk="0-2" data-fragment-id="0-2-0f-fallback"></template><!--/--></div><!--/--></div>"#
+        );
+    });
+}
+
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+#[test]
+fn ssr_test_suspense_timeout() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, use_context, Scope, SuspenseContext};
+    use std::time::{Duration, Instant};
+
+    #[component]
+    fn Pending(cx: Scope) -> Element {
+        use_context::<SuspenseContext>(cx).unwrap().increment();
+        view! { cx, <span>"Pending"</span> }
+    }
+
+    _ = create_scope(move |cx| {
+        _ = view! {
+            cx,
+            <div>
+                <Suspense fallback={"Loading"} timeout_ms=100 on_timeout=|cx| view! { cx, <p>"Timed out"</p> }>
+                    <Pending/>
+                </Suspense>
+            </div>
+        };
+        let (_, fragment) = cx.pending_fragments().into_iter().next().unwrap();
+
+        // the children never load, so `on_timeout` is streamed in once the timeout is up; the
+        // timer doesn't need a tokio runtime
+        let start = Instant::now();
+        let html = futures::executor::block_on(fragment);
+        assert_eq!(html, r#"<p data-hk="0-3">Timed out</p>"#);
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    });
+}
+
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+#[test]
+fn ssr_test_suspense_min_duration() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, use_context, Scope, SuspenseContext};
+    use std::{
+        cell::Cell,
+        time::{Duration, Instant},
+    };
+
+    thread_local! {
+        static BOUNDARY: Cell<Option<SuspenseContext>> = const { Cell::new(None) };
+    }
+
+    #[component]
+    fn Pending(cx: Scope) -> Element {
+        // like a resource, this only suspends its boundary until it has loaded once
+        let boundary = use_context::<SuspenseContext>(cx).unwrap();
+        if BOUNDARY.with(|b| b.replace(Some(boundary))) != Some(boundary) {
+            boundary.increment();
+        }
+        view! { cx, <span>"Loaded"</span> }
+    }
+
+    // waits for the boundary's fragments once its children have loaded, returning each one's HTML
+    // and how long it took
+    let resolve = move |cx: Scope| {
+        BOUNDARY.with(|b| b.get().unwrap()).decrement();
+        let mut fragments = cx
+            .pending_fragments()
+            .into_iter()
+            .map(|(key, fragment)| {
+                let start = Instant::now();
+                let html = futures::executor::block_on(fragment);
+                (key, html, start.elapsed())
+            })
+            .collect::<Vec<_>>();
+        fragments.sort();
+        fragments
+    };
+
+    _ = create_scope(move |cx| {
+        // once the fallback is shown, it's kept for at least `min_duration_ms`...
+        _ = view! {
+            cx,
+            <div>
+                <Suspense fallback={"Loading"} min_duration_ms=100>
+                    <Pending/>
+                </Suspense>
+            </div>
+        };
+        let fragments = resolve(cx);
+        assert_eq!(fragments.len(), 1);
+        let (key, html, elapsed) = &fragments[0];
+        assert_eq!(key, "0-2-0f");
+        assert_eq!(html, r#"<span data-hk="0-4-0">Loaded</span>"#);
+        assert!(*elapsed >= Duration::from_millis(100));
+
+        // ...but if the children load within `delay_ms`, it's never shown, so there's nothing to wait for
+        _ = view! {
+            cx,
+            <div>
+                <Suspense fallback={"Loading"} delay_ms=20 min_duration_ms=100>
+                    <Pending/>
+                </Suspense>
+            </div>
+        };
+        let fragments = resolve(cx);
+        assert_eq!(fragments.len(), 2);
+        let (key, html, elapsed) = &fragments[0];
+        assert_eq!(key, "0-6-0f");
+        assert_eq!(html, r#"<span data-hk="0-9-0">Loaded</span>"#);
+        assert!(*elapsed < Duration::from_millis(100));
+        let (key, html, elapsed) = &fragments[1];
+        assert_eq!(key, "0-6-0f-fallback");
+        assert_eq!(html, "");
+        assert!(*elapsed >= Duration::from_millis(20));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_suspense_list() {
+    use leptos_core as leptos;
+    use leptos_core::*;
+    use leptos_dom::*;
+    use leptos_macro::*;
+    use leptos_reactive::{create_scope, use_context, Scope, SuspenseContext};
+
+    #[component]
+    fn Pending(cx: Scope) -> Element {
+        use_context::<SuspenseContext>(cx).unwrap().increment();
+        view! { cx, <span>"Pending"</span> }
+    }
+
+    _ = create_scope(|cx| {
+        let forwards = view! {
+            cx,
+            <div>
+                <SuspenseList reveal_order="forwards">
+                    <Suspense fallback={"Loading A"}><p>"A"</p></Suspense>
+                    <Suspense fallback={"Loading B"}><Pending/></Suspense>
+                    <Suspense fallback={"Loading C"}><p>"C"</p></Suspense>
+                </SuspenseList>
+            </div>
+        };
+        assert_eq!(
+            forwards,
+            r#"<div data-hk="0-0"><!--#--><p data-hk="0-2-1-0">A</p><div data-hk="0-2-3" data-fragment-id="0-2-2-0f"><!--#-->Loading B<!--/--></div><div data-hk="0-2-4" data-fragment-id="0-2-3-0f"><!--#-->Loading C<!--/--></div><!--/--></div>"#
+        );
+
+        let together = view! {
+            cx,
+            <div>
+                <SuspenseList reveal_order="together">
+                    <Suspense fallback={"Loading A"}><p>"A"</p></Suspense>
+                    <Suspense fallback={"Loading B"}><Pending/></Suspense>
+                </SuspenseList>
+            </div>
+        };
+        assert_eq!(
+            together,
+            r#"<div data-hk="0-2"><!--#--><div data-hk="0-4-2" data-fragment-id="0-4-1-0f"><!--#-->Loading A<!--/--></div><div data-hk="0-4-3" data-fragment-id="0-4-2-0f"><!--#-->Loading B<!--/--></div><!--/--></div>"#
+        );
+    });
+}
+
 #[cfg(not(any(feature = "csr", feature = "hydrate")))]
 #[test]
 fn test_classes() {
@@ -82,6 +772,94 @@ fn test_classes() {
     });
 }
 
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn test_class_lists() {
+    use leptos_dom::*;
+    use leptos_macro::view;
+    use leptos_reactive::{create_scope, create_signal};
+
+    _ = create_scope(|cx| {
+        let (value, set_value) = create_signal(cx, 5);
+        let rendered = view! {
+            cx,
+            <div class={move || vec![("my", true), ("big", value() > 1), ("small", value() < 1)]} class:red=true></div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0" class="my big red"></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn test_class_list_variables() {
+    use leptos_dom::*;
+    use leptos_macro::view;
+    use leptos_reactive::{create_scope, create_signal};
+    use std::collections::HashSet;
+
+    _ = create_scope(|cx| {
+        let (value, set_value) = create_signal(cx, 5);
+        let cls = move || if value() > 1 { "big" } else { "small" };
+        let names = HashSet::from(["c", "a", "b"]);
+        let rendered = view! {
+            cx,
+            <div class=cls class:red=true><span class=names></span></div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0" class="big red"><span class="a b c"></span></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn test_styles() {
+    use leptos_dom::*;
+    use leptos_macro::view;
+    use leptos_reactive::{create_scope, create_signal};
+
+    _ = create_scope(|cx| {
+        let (value, set_value) = create_signal(cx, 5);
+        let rendered = view! {
+            cx,
+            <div style="padding: 1em;" style:color={move || (value() > 10).then(|| "red")} style:width="10px" style-font-size={move || format!("{}px", value())}></div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0" style="padding: 1em;width: 10px;font-size: 5px;"></div>"#
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn test_style_variables() {
+    use leptos_dom::*;
+    use leptos_macro::view;
+    use leptos_reactive::{create_scope, create_signal};
+
+    _ = create_scope(|cx| {
+        let (value, set_value) = create_signal(cx, 5);
+        let base = move || format!("padding: {}px;", value());
+        let rendered = view! {
+            cx,
+            <div style=base style:color="red"></div>
+        };
+
+        assert_eq!(
+            rendered,
+            r#"<div data-hk="0-0" style="padding: 5px;color: red;"></div>"#
+        );
+    });
+}
+
 #[cfg(not(any(feature = "csr", feature = "hydrate")))]
 #[test]
 fn test_dash_prefixes() {
@@ -111,3 +889,26 @@ fn test_dash_prefixes() {
         "<div data-hk=\"0-0\" class=\"my big  red car\" id=\"id\"></div>"
     );
 }
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_test_nested_svg_elements() {
+    use leptos_dom::*;
+    use leptos_macro::view;
+    use leptos_reactive::run_scope;
+
+    let rendered = run_scope(|cx| {
+        view! {
+            cx,
+            <svg viewBox="0 0 100 100">
+                <a href="/home"><text x="10" y="20">"Home"</text></a>
+                <image href="/logo.png"/>
+            </svg>
+        }
+    });
+
+    assert_eq!(
+        rendered,
+        "<svg data-hk=\"0-0\" viewBox=\"0 0 100 100\"><a href=\"/home\"><text x=\"10\" y=\"20\">Home</text></a><image href=\"/logo.png\"></image></svg>"
+    );
+}
@@ -0,0 +1,55 @@
+use std::{cell::RefCell, rc::Rc};
+
+use leptos_dom::{Child, IntoChild};
+use leptos_reactive::{create_memo, Memo, Scope, ScopeDisposer};
+
+/// A view that is created in its own [Scope], like the children of [Show](crate::Show)
+/// or one of the branches of a [Switch](crate::Switch).
+pub type ScopedChild = Rc<dyn Fn(Scope) -> Child>;
+
+pub(crate) fn scoped_child<E: IntoChild>(f: impl Fn(Scope) -> E + 'static) -> ScopedChild {
+    Rc::new(move |cx| f(cx).into_child(cx))
+}
+
+/// A function that creates a view in a given [Scope], like the `fallback` of [Show](crate::Show).
+/// It can return anything that implements [IntoChild], just like the function passed as children.
+pub trait ScopedView: Fn(Scope) -> <Self as ScopedView>::View + 'static {
+    /// The type of view the function returns.
+    type View: IntoChild;
+}
+
+impl<F, E> ScopedView for F
+where
+    F: Fn(Scope) -> E + 'static,
+    E: IntoChild,
+{
+    type View = E;
+}
+
+// Renders whichever branch `key` points to in a new child scope. The branch is only created
+// again when the key changes, and the previous branch's scope is disposed when it does.
+pub(crate) fn render_branch<K>(
+    cx: Scope,
+    key: Memo<K>,
+    render: impl Fn(Scope, &K) -> Child + 'static,
+) -> impl Fn() -> Child
+where
+    K: PartialEq + std::fmt::Debug + Clone + 'static,
+{
+    let disposer: Rc<RefCell<Option<ScopeDisposer>>> = Default::default();
+    let branch = create_memo(cx, move |_| {
+        let key = key.get();
+        if let Some(disposer) = disposer.borrow_mut().take() {
+            disposer.dispose();
+        }
+
+        let mut child = Child::Null;
+        let new_disposer = cx.child_scope(|cx| {
+            // reads in the branch itself shouldn't cause it to be created again
+            child = cx.untrack(|| render(cx, &key));
+        });
+        *disposer.borrow_mut() = Some(new_disposer);
+        child
+    });
+    move || branch.get()
+}
@@ -0,0 +1,101 @@
+use crate as leptos;
+use crate::branch::{render_branch, scoped_child, ScopedChild};
+use leptos_dom::{Child, IntoChild};
+use leptos_macro::Props;
+use leptos_reactive::{create_memo, Scope};
+use std::rc::Rc;
+
+/// Properties for the [Dynamic](crate::Dynamic) component.
+#[derive(Props)]
+pub struct DynamicProps<C, T>
+where
+    C: Fn() -> T,
+    T: Into<DynamicComponent>,
+{
+    /// Returns the component to show. This can be a [DynamicComponent], or any function or
+    /// closure that renders a view in a [Scope].
+    pub component: C,
+}
+
+/// Shows the view rendered by whichever component `component` returns.
+///
+/// Components are compared by identity: the view is only created again when `component` returns
+/// a different [DynamicComponent] than it did before. A function or closure is wrapped in a new
+/// [DynamicComponent] each time it's returned, so it's treated as a new view whenever `component`
+/// runs again. Each time, the view is created in a new [Scope], which is disposed when it's
+/// replaced.
+///
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::*; use leptos_core::*;
+/// # use leptos_dom as leptos; use leptos_dom::wasm_bindgen::JsCast;
+/// fn admin_panel(cx: Scope) -> Element {
+///     view! { cx, <p>"Admin"</p> }
+/// }
+///
+/// fn user_panel(cx: Scope) -> Element {
+///     view! { cx, <p>"User"</p> }
+/// }
+///
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (is_admin, set_is_admin) = create_signal(cx, false);
+/// let (admin, user) = (DynamicComponent::new(admin_panel), DynamicComponent::new(user_panel));
+/// view! {
+///   cx,
+///   <div>
+///     <Dynamic component={move || if is_admin() { admin.clone() } else { user.clone() }}/>
+///   </div>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+#[allow(non_snake_case)]
+pub fn Dynamic<C, T>(cx: Scope, props: DynamicProps<C, T>) -> impl Fn() -> Child
+where
+    C: Fn() -> T + 'static,
+    T: Into<DynamicComponent>,
+{
+    let component = props.component;
+    let component = create_memo(cx, move |_| component().into());
+
+    render_branch(cx, component, |cx, component| (component.0)(cx))
+}
+
+/// A component shown by [Dynamic](crate::Dynamic). Clones of the same `DynamicComponent` are
+/// equal to one another, so returning one again doesn't create its view again.
+#[derive(Clone)]
+pub struct DynamicComponent(ScopedChild);
+
+impl DynamicComponent {
+    /// Wraps a function that renders a view in a [Scope].
+    pub fn new<F, E>(f: F) -> Self
+    where
+        F: Fn(Scope) -> E + 'static,
+        E: IntoChild,
+    {
+        Self(scoped_child(f))
+    }
+}
+
+impl<F, E> From<F> for DynamicComponent
+where
+    F: Fn(Scope) -> E + 'static,
+    E: IntoChild,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+impl PartialEq for DynamicComponent {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for DynamicComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DynamicComponent").finish()
+    }
+}
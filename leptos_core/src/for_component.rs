@@ -1,4 +1,4 @@
-use leptos_dom::Element;
+use leptos_dom::{Child, IntoChild};
 use leptos_macro::*;
 use leptos_reactive::{Memo, Scope};
 use std::fmt::Debug;
@@ -9,10 +9,11 @@ use crate::map::map_keyed;
 
 /// Properties for the [For](crate::For) component.
 #[derive(Props)]
-pub struct ForProps<E, T, G, I, K>
+pub struct ForProps<E, T, G, C, I, K>
 where
     E: Fn() -> Vec<T>,
-    G: Fn(Scope, &T) -> Element,
+    G: Fn(Scope, &T) -> C,
+    C: IntoChild,
     I: Fn(&T) -> K,
     K: Eq + Hash,
     T: Eq + 'static,
@@ -26,16 +27,45 @@ where
 ///
 /// This is much more efficient than naively iterating over nodes with `.iter().map(|n| view! { cx,  ... })...`,
 /// as it avoids re-creating DOM nodes that are not being changed.
+///
+/// Each row can render anything that implements [IntoChild]: a single element, text, a fragment
+/// of several elements (like a set of `<td>`s), or a component. Rows made of several nodes are
+/// moved and removed together.
 #[allow(non_snake_case)]
-pub fn For<E, T, G, I, K>(cx: Scope, props: ForProps<E, T, G, I, K>) -> Memo<Vec<Element>>
-//-> impl FnMut() -> Vec<Element>
+pub fn For<E, T, G, C, I, K>(cx: Scope, props: ForProps<E, T, G, C, I, K>) -> Memo<Vec<Child>>
 where
     E: Fn() -> Vec<T> + 'static,
-    G: Fn(Scope, &T) -> Element + 'static,
+    G: Fn(Scope, &T) -> C + 'static,
+    C: IntoChild,
     I: Fn(&T) -> K + 'static,
     K: Eq + Hash,
     T: Eq + Debug + 'static,
 {
     let map_fn = (props.children)().swap_remove(0);
-    map_keyed(cx, props.each, map_fn, props.key)
+    map_keyed(
+        cx,
+        props.each,
+        move |cx, item| into_row(cx, map_fn(cx, item)),
+        props.key,
+    )
+}
+
+/// Converts the contents of a list row into a [Child]. Static text is rendered into a text node
+/// once, so that the row keeps the same node when the list is reconciled. Reactive rows are
+/// rendered into their own range of nodes, which they update without re-rendering the list.
+pub(crate) fn into_row(cx: Scope, child: impl IntoChild) -> Child {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        use leptos_dom::wasm_bindgen::JsCast;
+
+        match child.into_child(cx) {
+            Child::Text(text) => Child::Node(leptos_dom::create_text_node(&text).unchecked_into()),
+            Child::Fn(f) => leptos_dom::insert_range(cx, f),
+            child => child,
+        }
+    }
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    {
+        child.into_child(cx)
+    }
 }
@@ -0,0 +1,38 @@
+use leptos_dom::{Child, IntoChild};
+use leptos_macro::*;
+use leptos_reactive::{Memo, ReadSignal, Scope};
+
+use crate as leptos;
+use crate::for_component::into_row;
+use crate::map::map_indexed;
+
+/// Properties for the [Index](crate::Index) component.
+#[derive(Props)]
+pub struct IndexProps<E, T, G, C>
+where
+    E: Fn() -> Vec<T>,
+    G: Fn(Scope, ReadSignal<T>) -> C,
+    C: IntoChild,
+    T: PartialEq + 'static,
+{
+    pub each: E,
+    pub children: Box<dyn Fn() -> Vec<G>>,
+}
+
+/// Iterates over children and displays them, by their position in the list rather than by key.
+///
+/// Each row receives a [ReadSignal] holding the item at its position. When that item changes,
+/// the signal is updated and the row is kept, rather than being re-created. This makes `Index`
+/// a good fit for lists of primitive values, like a table of numbers or a list of text inputs,
+/// while [For](crate::For) is better for lists of items with a stable identity.
+#[allow(non_snake_case)]
+pub fn Index<E, T, G, C>(cx: Scope, props: IndexProps<E, T, G, C>) -> Memo<Vec<Child>>
+where
+    E: Fn() -> Vec<T> + 'static,
+    G: Fn(Scope, ReadSignal<T>) -> C + 'static,
+    C: IntoChild,
+    T: PartialEq + 'static,
+{
+    let map_fn = (props.children)().swap_remove(0);
+    map_indexed(cx, props.each, move |cx, item| into_row(cx, map_fn(cx, item)))
+}
@@ -1,10 +1,26 @@
+mod branch;
+mod dynamic;
 mod for_component;
+mod index_component;
 mod map;
+mod portal;
+mod show;
 mod suspense;
+mod suspense_list;
+mod switch;
+mod virtual_list;
 
+pub use branch::{ScopedChild, ScopedView};
+pub use dynamic::*;
 pub use for_component::*;
+pub use index_component::*;
 pub use map::*;
+pub use portal::*;
+pub use show::*;
 pub use suspense::*;
+pub use suspense_list::*;
+pub use switch::*;
+pub use virtual_list::*;
 
 pub trait Prop {
     type Builder;
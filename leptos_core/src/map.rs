@@ -1,4 +1,7 @@
-use leptos_reactive::{create_memo, queue_microtask, Memo, Scope, ScopeDisposer};
+use leptos_reactive::{
+    create_memo, create_signal, queue_microtask, Memo, ReadSignal, Scope, ScopeDisposer,
+    WriteSignal,
+};
 use std::{collections::HashMap, fmt::Debug, hash::Hash, ops::IndexMut};
 
 /// Function that maps a `Vec` to another `Vec` via a map function. The mapped `Vec` is lazy
@@ -153,9 +156,81 @@ where
     })
 }
 
+/// Function that maps a `Vec` to another `Vec` via a map function, without keys. Each entry
+/// of the mapped `Vec` is tied to a position in the input `Vec`, rather than to an item.
+///
+/// The map function receives a [ReadSignal] holding the item at its position. When the item at
+/// that position changes, the signal is updated in place rather than the row being re-created,
+/// so only the parts of the row that read the signal will update. Rows are only created or
+/// disposed when the length of the list changes.
+///
+/// This function is the underlying utility behind `Index`, and is a better fit than [map_keyed]
+/// for lists of primitive values that don't have a natural key, like numbers or text inputs.
+///
+/// # Params
+/// * `list` - The list to be mapped. It is obtained via an accessor function, so can be a ReadSignal, a Memo
+///   or a derived signal.
+/// * `map_fn` - A closure that maps from a signal of the item at each position to the output type.
+pub fn map_indexed<T, U>(
+    cx: Scope,
+    list: impl Fn() -> Vec<T> + 'static,
+    map_fn: impl Fn(Scope, ReadSignal<T>) -> U + 'static,
+) -> Memo<Vec<U>>
+where
+    T: PartialEq + 'static,
+    U: PartialEq + Debug + Clone + 'static,
+{
+    // Previous state used for diffing.
+    let mut disposers: Vec<ScopeDisposer> = Vec::new();
+    let mut signals: Vec<(ReadSignal<T>, WriteSignal<T>)> = Vec::new();
+    let mut mapped: Vec<U> = Vec::new();
+
+    create_memo(cx, move |_| {
+        let new_items = list();
+        let new_items_len = new_items.len();
+
+        for (idx, new_item) in new_items.into_iter().enumerate() {
+            if let Some((read, write)) = signals.get(idx) {
+                // Update existing rows in place, if their item has changed.
+                let changed = cx.untrack(|| read.with(|item| item != &new_item));
+                if changed {
+                    write.set(new_item);
+                }
+            } else {
+                // Create new rows for any items past the end of the old list.
+                let mut value: Option<U> = None;
+                let mut signal = None;
+                let new_disposer = cx.child_scope(|cx| {
+                    let (read, write) = create_signal(cx, new_item);
+                    value = Some(map_fn(cx, read));
+                    signal = Some((read, write));
+                });
+                mapped.push(value.unwrap());
+                signals.push(signal.unwrap());
+                disposers.push(new_disposer);
+            }
+        }
+
+        // Dispose of any rows past the end of the new list.
+        if disposers.len() > new_items_len {
+            let removed = disposers.split_off(new_items_len);
+            // delay disposal until after the current microtask
+            queue_microtask(move || {
+                for disposer in removed {
+                    disposer.dispose();
+                }
+            });
+        }
+        mapped.truncate(new_items_len);
+        signals.truncate(new_items_len);
+
+        mapped.to_vec()
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::map::map_keyed;
+    use crate::map::{map_indexed, map_keyed};
     use leptos_reactive::*;
 
     #[test]
@@ -184,4 +259,33 @@ mod tests {
             .dispose();
         }
     }
+
+    #[test]
+    fn test_map_indexed_updates_rows_in_place() {
+        // we can really only run this in SSR mode, so just ignore if we're in CSR or hydrate
+        if !cfg!(any(feature = "csr", feature = "hydrate")) {
+            create_scope(|cx| {
+                let (rows, set_rows) = create_signal(cx, vec![1, 2, 3]);
+                let (created, set_created) = create_signal(cx, 0);
+
+                let indexed = map_indexed(cx, rows, move |cx, value| {
+                    set_created.update(|n| *n += 1);
+                    create_memo(cx, move |_| value() * 10)
+                });
+
+                let values = move || indexed().iter().map(|row| row()).collect::<Vec<_>>();
+                assert_eq!(values(), vec![10, 20, 30]);
+                assert_eq!(created(), 3);
+
+                set_rows(vec![1, 5, 3, 4]);
+                assert_eq!(values(), vec![10, 50, 30, 40]);
+                assert_eq!(created(), 4);
+
+                set_rows(vec![7]);
+                assert_eq!(values(), vec![70]);
+                assert_eq!(created(), 4);
+            })
+            .dispose();
+        }
+    }
 }
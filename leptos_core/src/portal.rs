@@ -0,0 +1,98 @@
+use crate as leptos;
+use leptos_dom::{Child, IntoChild};
+use leptos_macro::Props;
+use leptos_reactive::Scope;
+
+/// Properties for the [Portal](crate::Portal) component.
+#[derive(Props)]
+pub struct PortalProps<G>
+where
+    G: IntoChild,
+{
+    /// The element the children are mounted into. Defaults to `document.body`.
+    #[builder(default, setter(strip_option))]
+    pub mount: Option<leptos_dom::web_sys::Element>,
+    pub children: Box<dyn Fn() -> Vec<G>>,
+}
+
+/// Renders its children into another part of the DOM (by default, the end of `document.body`)
+/// rather than where the `<Portal/>` is declared. This is useful for modals, tooltips, and toasts
+/// that need to escape containers with `overflow: hidden` or their own stacking context.
+///
+/// The children are still owned by the scope in which the portal is declared, so they can
+/// use its context, and they are removed when that scope is disposed.
+///
+/// Nothing is rendered during server-side rendering; the children are mounted once the app is
+/// running in the browser.
+#[allow(non_snake_case)]
+pub fn Portal<G>(cx: Scope, props: PortalProps<G>) -> Child
+where
+    G: IntoChild,
+{
+    render_portal(cx, props)
+}
+
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+fn render_portal<G>(cx: Scope, props: PortalProps<G>) -> Child
+where
+    G: IntoChild,
+{
+    use leptos_dom::{
+        create_comment_node, document, insert, remove_child, wasm_bindgen::JsCast, Marker,
+        UnwrapThrowExt,
+    };
+    use leptos_reactive::on_cleanup;
+
+    let mount = props
+        .mount
+        .unwrap_or_else(|| document().body().unwrap_throw().unchecked_into());
+
+    let mut children = (props.children)();
+    let child = if children.len() == 1 {
+        children.swap_remove(0).into_child(cx)
+    } else {
+        children
+            .into_iter()
+            .map(|child| child.into_child(cx))
+            .collect::<Vec<_>>()
+            .into_child(cx)
+    };
+
+    // the children are kept between these markers, so they can be removed whatever they are by then
+    let start = create_comment_node();
+    let end = create_comment_node();
+    mount.append_child(&start).unwrap_throw();
+    mount.append_child(&end).unwrap_throw();
+    insert(
+        cx,
+        mount.clone().unchecked_into(),
+        child,
+        Marker::BeforeChild(end.clone()),
+        None,
+    );
+    leptos_dom::event_delegation::delegate_shadow_root_events(cx, &mount);
+
+    on_cleanup(cx, move || {
+        while let Some(node) = start.next_sibling() {
+            remove_child(&mount, &node);
+            if node == end {
+                break;
+            }
+        }
+        remove_child(&mount, &start);
+    });
+
+    Child::Null
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+fn render_portal<G>(_cx: Scope, props: PortalProps<G>) -> Child
+where
+    G: IntoChild,
+{
+    // the children still have to be created here, even though they aren't rendered, so that they
+    // use up the same hydration keys (and resources) on the server as they do in the browser;
+    // otherwise everything hydrated after the portal would look for the wrong server-rendered node
+    _ = (props.children)();
+    Child::Null
+}
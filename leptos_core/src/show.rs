@@ -0,0 +1,66 @@
+use crate as leptos;
+use crate::branch::{render_branch, scoped_child, ScopedChild, ScopedView};
+use leptos_dom::{Child, IntoChild};
+use leptos_macro::Props;
+use leptos_reactive::{create_memo, Scope};
+
+/// Properties for the [Show](crate::Show) component.
+#[derive(Props)]
+pub struct ShowProps<W, G, E>
+where
+    W: Fn() -> bool,
+    G: Fn(Scope) -> E,
+    E: IntoChild,
+{
+    /// Whether the children should be shown.
+    pub when: W,
+    /// What to show instead when `when` is `false`. Shows nothing if not given.
+    #[builder(default, setter(transform = |fallback: impl ScopedView| Some(scoped_child(fallback))))]
+    pub fallback: Option<ScopedChild>,
+    pub children: Box<dyn Fn() -> Vec<G>>,
+}
+
+/// Shows its children when `when` is `true`, and the `fallback` (if any) when it's `false`.
+///
+/// Unlike a closure that returns an `Option`, the children are only created again when
+/// `when` actually changes from `false` to `true`. Each time they are shown, they are created
+/// in a new [Scope], which is disposed when they are hidden.
+///
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::*; use leptos_core::*;
+/// # use leptos_dom as leptos; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (count, set_count) = create_signal(cx, 5);
+/// view! {
+///   cx,
+///   <div>
+///     <Show when={move || count() > 3} fallback={|cx| view! { cx, <p>"Small"</p> }}>
+///       {|cx: Scope| view! { cx, <p>"Big"</p> }}
+///     </Show>
+///   </div>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+#[allow(non_snake_case)]
+pub fn Show<W, G, E>(cx: Scope, props: ShowProps<W, G, E>) -> impl Fn() -> Child
+where
+    W: Fn() -> bool + 'static,
+    G: Fn(Scope) -> E + 'static,
+    E: IntoChild,
+{
+    let when = props.when;
+    let when = create_memo(cx, move |_| when());
+    let child = (props.children)().swap_remove(0);
+    let fallback = props.fallback;
+
+    render_branch(cx, when, move |cx, shown| {
+        if *shown {
+            child(cx).into_child(cx)
+        } else {
+            fallback.as_ref().map(|f| f(cx)).unwrap_or(Child::Null)
+        }
+    })
+}
@@ -1,99 +1,393 @@
 use crate as leptos;
+use crate::suspense_list::SuspenseListContext;
+use crate::branch::{scoped_child, ScopedChild, ScopedView};
 use leptos_dom::{Child, IntoChild};
 use leptos_macro::Props;
 use leptos_reactive::{provide_context, Scope, SuspenseContext};
+use std::time::Duration;
 
 #[derive(Props)]
-pub struct SuspenseProps<F, E, G>
+pub struct SuspenseProps<F, G>
 where
     F: IntoChild + Clone,
-    E: IntoChild,
-    G: Fn() -> E,
+    G: IntoChild,
 {
     fallback: F,
+    /// How long to wait, in milliseconds, before showing the fallback, so that it doesn't flash
+    /// up when the children load quickly. During server-side rendering, this uses a timer that
+    /// works with any async executor.
+    #[builder(default, setter(strip_option))]
+    delay_ms: Option<u64>,
+    /// Once the fallback is shown, the minimum time to keep showing it, in milliseconds. During
+    /// server-side rendering, this uses a timer that works with any async executor.
+    #[builder(default, setter(strip_option))]
+    min_duration_ms: Option<u64>,
+    /// How long to wait, in milliseconds, before giving up on the children and showing `on_timeout`.
+    /// During server-side rendering, this uses a timer that works with any async executor.
+    #[builder(default, setter(strip_option))]
+    timeout_ms: Option<u64>,
+    /// Shown if the children haven't loaded within `timeout_ms`.
+    #[builder(default, setter(transform = |on_timeout: impl ScopedView| Some(scoped_child(on_timeout))))]
+    on_timeout: Option<ScopedChild>,
     children: Box<dyn Fn() -> Vec<G>>,
 }
 
+/// Shows a fallback while any [Resource](leptos_reactive::Resource)s read by its children are loading,
+/// and then shows its children.
+///
+/// A resource suspends the nearest `<Suspense/>` that is rendering it, so nested boundaries resolve
+/// independently: an outer boundary shows its children as soon as its own resources have loaded, while
+/// an inner boundary continues to show its fallback until its resources have loaded too. During
+/// server-side rendering, each boundary streams its HTML as soon as it is ready, and boundaries
+/// inside a streamed fragment are streamed after it.
+///
+/// Inside a [SuspenseList](crate::SuspenseList), a boundary also waits until the list allows it to
+/// be revealed.
+///
+/// The fallback can be delayed with `delay_ms`, and kept on screen for at least `min_duration_ms`
+/// once it has been shown, so that fast loads don't cause it to flash. If the children haven't
+/// loaded after `timeout_ms`, the boundary shows `on_timeout` instead of the fallback (or keeps
+/// showing the fallback, if there is no `on_timeout`). These apply during server-side rendering too:
+/// the fallback and the children are streamed as they would appear in the browser. On the server,
+/// these options use [futures-timer](https://docs.rs/futures-timer), which runs its timers on a
+/// background thread, so they don't depend on the async runtime; a boundary without them doesn't
+/// use any timers.
 #[allow(non_snake_case)]
-pub fn Suspense<F, E, G>(cx: Scope, props: SuspenseProps<F, E, G>) -> impl Fn() -> Child
+pub fn Suspense<F, G>(cx: Scope, props: SuspenseProps<F, G>) -> Child
 where
-    F: IntoChild + Clone,
-    E: IntoChild,
-    G: Fn() -> E + 'static,
+    F: IntoChild + Clone + 'static,
+    G: IntoChild + 'static,
 {
     let context = SuspenseContext::new(cx);
 
     // provide this SuspenseContext to any resources below it
-    provide_context(cx, context.clone());
+    provide_context(cx, context);
+
+    // register with the surrounding <SuspenseList/>, if any
+    let list = SuspenseListContext::current().map(|list| {
+        let index = list.register(context);
+        (list, index)
+    });
+    let revealed = move || match &list {
+        Some((list, index)) => list.can_reveal(*index),
+        None => context.ready(),
+    };
 
-    let child = (props.children)().swap_remove(0);
+    let children = props.children;
+    let render_children = move || {
+        // a nested boundary isn't part of this boundary's list
+        SuspenseListContext::outside_list(|| {
+            context.run_in(cx, || {
+                let mut children = children();
+                let mut child = if children.len() == 1 {
+                    children.swap_remove(0).into_child(cx)
+                } else {
+                    children
+                        .into_iter()
+                        .map(|child| child.into_child(cx))
+                        .collect::<Vec<_>>()
+                        .into_child(cx)
+                };
+                // resolve the child here, so any resources it reads suspend this boundary
+                while let Child::Fn(f) = child {
+                    child = (f.borrow_mut())();
+                }
+                child
+            })
+        })
+    };
 
-    render_suspense(cx, context, props.fallback.clone(), child)
+    let timing = Timing {
+        delay: props.delay_ms.map(Duration::from_millis),
+        min_duration: props.min_duration_ms.map(Duration::from_millis),
+        timeout: props.timeout_ms.map(Duration::from_millis),
+        on_timeout: props.on_timeout,
+    };
+
+    render_suspense(cx, props.fallback, timing, revealed, render_children).into_child(cx)
+}
+
+/// When a boundary shows its fallback, and what it shows if its children time out.
+#[derive(Clone)]
+#[cfg_attr(
+    not(any(feature = "csr", feature = "hydrate", feature = "ssr")),
+    allow(dead_code)
+)]
+struct Timing {
+    delay: Option<Duration>,
+    min_duration: Option<Duration>,
+    timeout: Option<Duration>,
+    on_timeout: Option<ScopedChild>,
+}
+
+impl Timing {
+    /// Whether none of the timing options are set, so no timers are needed.
+    #[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+    fn is_untimed(&self) -> bool {
+        self.delay.is_none() && self.min_duration.is_none() && self.timeout.is_none()
+    }
 }
 
 #[cfg(any(feature = "csr", feature = "hydrate"))]
-fn render_suspense<'a, F, E, G>(
+fn render_suspense<F>(
     cx: Scope,
-    context: SuspenseContext,
     fallback: F,
-    child: G,
+    timing: Timing,
+    revealed: impl Fn() -> bool + 'static,
+    render_children: impl Fn() -> Child + 'static,
 ) -> impl Fn() -> Child
 where
-    F: IntoChild + Clone,
-    E: IntoChild,
-    G: Fn() -> E,
+    F: IntoChild + Clone + 'static,
 {
-    move || {
+    use leptos_dom::set_timeout;
+    use leptos_reactive::{create_effect, create_memo, create_signal};
+    use std::{cell::Cell, rc::Rc};
+
+    let Timing {
+        delay,
+        min_duration,
+        timeout,
+        on_timeout,
+    } = timing;
+    let revealed = create_memo(cx, move |_| revealed());
+
+    // whether `delay_ms` has passed, so the fallback can be shown
+    let (delay_passed, set_delay_passed) = create_signal(cx, delay.is_none());
+    // whether the fallback is being kept on screen for `min_duration_ms`
+    let (holding, set_holding) = create_signal(cx, false);
+    let (timed_out, set_timed_out) = create_signal(cx, false);
+    // changes whenever the boundary starts or stops waiting, so earlier timers are ignored
+    let wait_id = Rc::new(Cell::new(0_usize));
+
+    let show_fallback = move || {
+        set_delay_passed.set(true);
+        if let Some(min_duration) = min_duration {
+            set_holding.set(true);
+            set_timeout(move || set_holding.set(false), min_duration);
+        }
+    };
+
+    create_effect(cx, move |_| {
+        let is_revealed = revealed.get();
+        let id = wait_id.get() + 1;
+        wait_id.set(id);
+        set_timed_out.set(false);
+
+        if !is_revealed {
+            match delay {
+                Some(delay) => {
+                    set_delay_passed.set(false);
+                    let wait_id = Rc::clone(&wait_id);
+                    set_timeout(
+                        move || {
+                            if wait_id.get() == id {
+                                show_fallback();
+                            }
+                        },
+                        delay,
+                    );
+                }
+                None => show_fallback(),
+            }
+
+            if let Some(timeout) = timeout {
+                let wait_id = Rc::clone(&wait_id);
+                set_timeout(
+                    move || {
+                        if wait_id.get() == id {
+                            leptos_dom::debug_warn!(
+                                "<Suspense/> timed out after {}ms",
+                                timeout.as_millis()
+                            );
+                            set_timed_out.set(true);
+                        }
+                    },
+                    timeout,
+                );
+            }
+        }
+    });
+
+    let child = create_memo(cx, move |prev: Option<&Child>| {
         #[cfg(feature = "transition")]
         let transition_pending = cx.transition_pending();
 
         #[cfg(not(feature = "transition"))]
         let transition_pending = false;
 
-        if context.ready() || transition_pending {
-            (child)().into_child(cx)
-        } else {
+        if (revealed.get() && !holding.get()) || transition_pending {
+            render_children()
+        } else if timed_out.get() {
+            match &on_timeout {
+                Some(on_timeout) => on_timeout(cx),
+                None => fallback.clone().into_child(cx),
+            }
+        } else if delay_passed.get() {
             fallback.clone().into_child(cx)
+        } else {
+            // still within `delay_ms`, so keep showing whatever was there before
+            prev.cloned().unwrap_or(Child::Null)
         }
-    }
+    });
+    move || child.get()
 }
 
-#[cfg(not(any(feature = "csr", feature = "hydrate")))]
-fn render_suspense<'a, F, E, G>(
+#[cfg(all(feature = "ssr", not(any(feature = "csr", feature = "hydrate"))))]
+fn render_suspense<F>(
     cx: Scope,
-    context: SuspenseContext,
     fallback: F,
-    orig_child: G,
+    timing: Timing,
+    revealed: impl Fn() -> bool + 'static,
+    render_children: impl Fn() -> Child + 'static,
 ) -> impl Fn() -> Child
 where
-    F: IntoChild + Clone,
-    E: IntoChild,
-    G: Fn() -> E + 'static,
+    F: IntoChild + Clone + 'static,
 {
+    use futures::future::{select, Either};
+    use futures_timer::Delay;
     use leptos_dom::IntoAttribute;
     use leptos_macro::view;
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Instant,
+    };
 
-    let initial = {
-        // run the child; we'll probably throw this away, but it will register resource reads
-        let mut child = orig_child().into_child(cx);
-        while let Child::Fn(f) = child {
-            child = (f.borrow_mut())();
-        }
+    let key = cx.current_fragment_key();
 
-        // no resources were read under this, so just return the child
-        if context.pending_resources.get() == 0 {
-            child
-        }
-        // show the fallback, but also prepare to stream HTML
-        else {
-            let key = cx.current_fragment_key();
-            cx.register_suspense(context, &key, move || {
-                orig_child().into_child(cx).as_child_string()
-            });
-
-            // return the fallback for now, wrapped in fragment identifer
-            Child::Node(view! { cx, <div data-fragment-id=key>{fallback.into_child(cx)}</div> })
+    // run the children; we'll probably throw this away, but it will register resource reads
+    let child = render_children();
+
+    // whether to show the children is decided when this is first rendered, rather than now,
+    // so that a <SuspenseList/> knows about all of its boundaries
+    let initial: RefCell<Option<Child>> = Default::default();
+    let revealed = Rc::new(revealed);
+    let render_children = Rc::new(render_children);
+    move || {
+        initial
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                // no resources were read under this, so just return the child
+                if revealed() {
+                    child.clone()
+                }
+                // show the fallback, but also prepare to stream HTML
+                else if timing.is_untimed() {
+                    let revealed = Rc::clone(&revealed);
+                    let render_children = Rc::clone(&render_children);
+                    cx.register_fragment(&key, move || revealed(), move || {
+                        render_children().as_child_string()
+                    });
+
+                    // return the fallback for now, wrapped in fragment identifer
+                    let fallback = fallback.clone();
+                    let key = key.clone();
+                    Child::Node(
+                        view! { cx, <div data-fragment-id=key>{fallback.into_child(cx)}</div> },
+                    )
+                }
+                // the timing options need timers, which don't rely on any particular runtime
+                else {
+                    let Timing {
+                        delay,
+                        min_duration,
+                        timeout,
+                        on_timeout,
+                    } = timing.clone();
+                    // when the fallback was shown, if it has been
+                    let fallback_shown: Rc<Cell<Option<Instant>>> = Default::default();
+
+                    // with a delay, the fallback is streamed later if the children still aren't ready
+                    let placeholder = match delay {
+                        Some(delay) => {
+                            let fallback_key = format!("{key}-fallback");
+                            let fallback = fallback.clone();
+                            let revealed = Rc::clone(&revealed);
+                            let fallback_shown = Rc::clone(&fallback_shown);
+                            cx.register_fragment_future(&fallback_key, async move {
+                                Delay::new(delay).await;
+                                if revealed() {
+                                    String::new()
+                                } else {
+                                    fallback_shown.set(Some(Instant::now()));
+                                    fallback.into_child(cx).as_child_string()
+                                }
+                            });
+                            Child::Node(
+                                view! { cx, <template data-fragment-id=fallback_key></template> },
+                            )
+                        }
+                        None => {
+                            fallback_shown.set(Some(Instant::now()));
+                            fallback.clone().into_child(cx)
+                        }
+                    };
+
+                    let ready = cx.until({
+                        let revealed = Rc::clone(&revealed);
+                        move || revealed()
+                    });
+                    let render_children = Rc::clone(&render_children);
+                    let content = async move {
+                        ready.await;
+                        // once the fallback has been shown, keep it for the minimum duration
+                        if let (Some(min_duration), Some(shown)) =
+                            (min_duration, fallback_shown.get())
+                        {
+                            Delay::new(min_duration.saturating_sub(shown.elapsed())).await;
+                        }
+                        render_children().as_child_string()
+                    };
+
+                    match timeout {
+                        Some(timeout) => {
+                            let fallback = fallback.clone();
+                            cx.register_fragment_future(&key, async move {
+                                let timer = Delay::new(timeout);
+                                match select(Box::pin(content), Box::pin(timer)).await {
+                                    Either::Left((html, _)) => html,
+                                    Either::Right(_) => {
+                                        leptos_dom::debug_warn!(
+                                            "<Suspense/> timed out after {}ms",
+                                            timeout.as_millis()
+                                        );
+                                        match on_timeout {
+                                            Some(on_timeout) => on_timeout(cx).as_child_string(),
+                                            None => fallback.into_child(cx).as_child_string(),
+                                        }
+                                    }
+                                }
+                            });
+                        }
+                        None => cx.register_fragment_future(&key, content),
+                    }
+
+                    // return the fallback for now, wrapped in fragment identifer
+                    let key = key.clone();
+                    Child::Node(view! { cx, <div data-fragment-id=key>{placeholder}</div> })
+                }
+            })
+            .clone()
+    }
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+fn render_suspense<F>(
+    cx: Scope,
+    fallback: F,
+    _timing: Timing,
+    revealed: impl Fn() -> bool + 'static,
+    render_children: impl Fn() -> Child + 'static,
+) -> impl Fn() -> Child
+where
+    F: IntoChild + Clone + 'static,
+{
+    // without a renderer there's nothing to stream to, so just show the fallback until ready
+    move || {
+        if revealed() {
+            render_children()
+        } else {
+            fallback.clone().into_child(cx)
         }
-    };
-    move || initial.clone()
+    }
 }
@@ -0,0 +1,156 @@
+use crate as leptos;
+use leptos_dom::{Child, IntoChild};
+use leptos_macro::Props;
+use leptos_reactive::{create_memo, Scope, SuspenseContext};
+use std::{cell::RefCell, rc::Rc};
+
+thread_local! {
+    // the <SuspenseList/> whose children are currently being created, if any
+    static CURRENT_LIST: RefCell<Vec<Option<SuspenseListContext>>> = Default::default();
+}
+
+/// The order in which a [SuspenseList](crate::SuspenseList) reveals its boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevealOrder {
+    /// Each boundary is revealed once it and all the boundaries before it are ready.
+    Forwards,
+    /// All the boundaries are revealed at once, when all of them are ready.
+    Together,
+}
+
+impl From<&str> for RevealOrder {
+    fn from(value: &str) -> Self {
+        match value {
+            "forwards" => RevealOrder::Forwards,
+            "together" => RevealOrder::Together,
+            _ => {
+                leptos_dom::debug_warn!(
+                    "<SuspenseList/> reveal_order should be \"forwards\" or \"together\", not {value:?}"
+                );
+                RevealOrder::Forwards
+            }
+        }
+    }
+}
+
+/// Tracks the [Suspense](crate::Suspense) boundaries in a [SuspenseList](crate::SuspenseList).
+#[derive(Debug, Clone)]
+pub(crate) struct SuspenseListContext {
+    reveal_order: RevealOrder,
+    boundaries: Rc<RefCell<Vec<SuspenseContext>>>,
+}
+
+impl SuspenseListContext {
+    /// The list whose children are being created, if a boundary is being created directly inside one.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT_LIST.with(|lists| lists.borrow().last().cloned().flatten())
+    }
+
+    /// Runs `f` without a current list, so boundaries created within it aren't added to any list.
+    pub(crate) fn outside_list<T>(f: impl FnOnce() -> T) -> T {
+        Self::with_list(None, f)
+    }
+
+    fn with_list<T>(list: Option<Self>, f: impl FnOnce() -> T) -> T {
+        // pops the list even if `f` panics, so later boundaries don't register with it
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                CURRENT_LIST.with(|lists| lists.borrow_mut().pop());
+            }
+        }
+
+        CURRENT_LIST.with(|lists| lists.borrow_mut().push(list));
+        let _guard = Guard;
+        f()
+    }
+
+    /// Adds a boundary to the list, returning its position.
+    pub(crate) fn register(&self, boundary: SuspenseContext) -> usize {
+        let mut boundaries = self.boundaries.borrow_mut();
+        boundaries.push(boundary);
+        boundaries.len() - 1
+    }
+
+    /// Whether the boundary at `index` can show its children.
+    pub(crate) fn can_reveal(&self, index: usize) -> bool {
+        let boundaries = self.boundaries.borrow();
+        match self.reveal_order {
+            RevealOrder::Forwards => boundaries[..=index].iter().all(SuspenseContext::ready),
+            RevealOrder::Together => boundaries.iter().all(SuspenseContext::ready),
+        }
+    }
+}
+
+/// Properties for the [SuspenseList](crate::SuspenseList) component.
+#[derive(Props)]
+pub struct SuspenseListProps {
+    /// Either `"forwards"` or `"together"`.
+    #[builder(setter(into))]
+    reveal_order: RevealOrder,
+    children: Box<dyn Fn() -> Vec<Child>>,
+}
+
+/// Coordinates the [Suspense](crate::Suspense) boundaries inside it, so that they aren't revealed
+/// in whatever order their data happens to arrive.
+///
+/// With `reveal_order="forwards"`, a boundary is only revealed once every boundary before it has been
+/// revealed. With `reveal_order="together"`, every boundary continues to show its fallback until all of
+/// them are ready.
+///
+/// Only the boundaries that are direct children of the list take part; a `<Suspense/>` nested
+/// inside one of them is revealed on its own.
+#[allow(non_snake_case)]
+pub fn SuspenseList(cx: Scope, props: SuspenseListProps) -> impl Fn() -> Child {
+    let list = SuspenseListContext {
+        reveal_order: props.reveal_order,
+        boundaries: Default::default(),
+    };
+
+    // the boundaries register with the list as they're created
+    let children = SuspenseListContext::with_list(Some(list), || (props.children)());
+
+    // each boundary is memoized, so that revealing one doesn't render the others again
+    let children = children
+        .into_iter()
+        .map(|child| {
+            create_memo(cx, move |_| {
+                let mut child = child.clone();
+                while let Child::Fn(f) = child {
+                    child = (f.borrow_mut())();
+                }
+                child
+            })
+        })
+        .collect::<Vec<_>>();
+
+    move || {
+        children
+            .iter()
+            .map(|child| child.get())
+            .collect::<Vec<_>>()
+            .into_child(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RevealOrder, SuspenseListContext};
+
+    #[test]
+    fn list_is_popped_after_a_panic() {
+        let list = SuspenseListContext {
+            reveal_order: RevealOrder::Forwards,
+            boundaries: Default::default(),
+        };
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            SuspenseListContext::with_list(Some(list), || {
+                assert!(SuspenseListContext::current().is_some());
+                panic!("rendering failed");
+            })
+        }));
+        assert!(panicked.is_err());
+        assert!(SuspenseListContext::current().is_none());
+    }
+}
@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+use crate as leptos;
+use crate::branch::{render_branch, scoped_child, ScopedChild, ScopedView};
+use leptos_dom::{Child, IntoChild};
+use leptos_macro::Props;
+use leptos_reactive::{create_memo, Scope};
+
+/// Properties for the [Switch](crate::Switch) component.
+#[derive(Props)]
+pub struct SwitchProps {
+    /// What to show when none of the branches match. Shows nothing if not given.
+    #[builder(default, setter(transform = |fallback: impl ScopedView| Some(scoped_child(fallback))))]
+    pub fallback: Option<ScopedChild>,
+    pub children: Box<dyn Fn() -> Vec<MatchBranch>>,
+}
+
+/// Shows the first of its [Match] children whose `when` condition is `true`,
+/// or the `fallback` if none of them are.
+///
+/// The active branch is only created again when a different branch starts to match. Each
+/// branch is created in a new [Scope], which is disposed when it stops being shown.
+///
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::*; use leptos_core::*;
+/// # use leptos_dom as leptos; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (count, set_count) = create_signal(cx, 5);
+/// view! {
+///   cx,
+///   <div>
+///     <Switch fallback={|cx| view! { cx, <p>"Medium"</p> }}>
+///       <Match when={move || count() > 10}>{|cx: Scope| view! { cx, <p>"Big"</p> }}</Match>
+///       <Match when={move || count() < 3}>{|cx: Scope| view! { cx, <p>"Small"</p> }}</Match>
+///     </Switch>
+///   </div>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+#[allow(non_snake_case)]
+pub fn Switch(cx: Scope, props: SwitchProps) -> impl Fn() -> Child {
+    let branches = Rc::new((props.children)());
+    let fallback = props.fallback;
+
+    let active = create_memo(cx, {
+        let branches = Rc::clone(&branches);
+        move |_| branches.iter().position(|branch| (branch.when)())
+    });
+
+    render_branch(cx, active, move |cx, active| match active {
+        Some(idx) => (branches[*idx].render)(cx),
+        None => fallback.as_ref().map(|f| f(cx)).unwrap_or(Child::Null),
+    })
+}
+
+/// Properties for the [Match] component.
+#[derive(Props)]
+pub struct MatchProps<W, G, E>
+where
+    W: Fn() -> bool,
+    G: Fn(Scope) -> E,
+    E: IntoChild,
+{
+    /// Whether this branch should be shown, if no earlier branch is.
+    pub when: W,
+    pub children: Box<dyn Fn() -> Vec<G>>,
+}
+
+/// One branch of a [Switch].
+#[allow(non_snake_case)]
+pub fn Match<W, G, E>(_cx: Scope, props: MatchProps<W, G, E>) -> MatchBranch
+where
+    W: Fn() -> bool + 'static,
+    G: Fn(Scope) -> E + 'static,
+    E: IntoChild,
+{
+    MatchBranch {
+        when: Rc::new(props.when),
+        render: scoped_child((props.children)().swap_remove(0)),
+    }
+}
+
+/// A branch of a [Switch], created by the [Match] component.
+#[derive(Clone)]
+pub struct MatchBranch {
+    when: Rc<dyn Fn() -> bool>,
+    render: ScopedChild,
+}
+
+impl std::fmt::Debug for MatchBranch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MatchBranch").finish()
+    }
+}
@@ -0,0 +1,383 @@
+use leptos_dom::{Child, Element, IntoChild};
+use leptos_macro::*;
+use leptos_reactive::{create_memo, create_signal, Memo, Scope, WriteSignal};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+    rc::Rc,
+};
+
+use crate as leptos;
+use crate::for_component::into_row;
+use crate::map::map_keyed;
+
+/// Properties for the [VirtualList](crate::VirtualList) component.
+#[derive(Props)]
+pub struct VirtualListProps<E, T, G, C, I, K>
+where
+    E: Fn() -> Vec<T>,
+    G: Fn(Scope, &T) -> C,
+    C: IntoChild,
+    I: Fn(&T) -> K,
+    K: Eq + Hash,
+    T: Eq + 'static,
+{
+    /// The items in the list.
+    pub each: E,
+    /// Returns a unique key for each item.
+    pub key: I,
+    /// The estimated height of a row, in pixels, which is used until the row has been measured.
+    pub item_height: f64,
+    /// The height of the scrolling viewport, in pixels.
+    pub height: f64,
+    /// The number of extra rows to render above and below the visible rows.
+    #[builder(default = 3)]
+    pub overscan: usize,
+    pub children: Box<dyn Fn() -> Vec<G>>,
+}
+
+/// A list that only renders the rows that are visible in its scrolling viewport, for lists
+/// too long to render with [For](crate::For).
+///
+/// Rows are keyed in the same way as [For](crate::For), so a row that stays in view keeps its
+/// scope and DOM nodes as the list scrolls or changes. Rows may have different heights: each row
+/// is measured once it has been rendered, and again whenever its size changes, and `item_height` is
+/// used as an estimate for any row that hasn't been measured yet.
+///
+/// During server-side rendering, the rows that fit in the top of the viewport are rendered, and
+/// hydrated in place in the browser.
+#[allow(non_snake_case)]
+pub fn VirtualList<E, T, G, C, I, K>(
+    cx: Scope,
+    props: VirtualListProps<E, T, G, C, I, K>,
+) -> Element
+where
+    E: Fn() -> Vec<T> + 'static,
+    G: Fn(Scope, &T) -> C + 'static,
+    C: IntoChild,
+    I: Fn(&T) -> K + 'static,
+    K: Eq + Hash + Clone + 'static,
+    T: Eq + Debug + Clone + 'static,
+{
+    let VirtualListProps {
+        each,
+        key,
+        item_height,
+        height,
+        overscan,
+        children,
+    } = props;
+    let key = Rc::new(key);
+    let map_fn = (children)().swap_remove(0);
+
+    let (scroll_top, set_scroll_top) = create_signal(cx, 0.0);
+    // heights of rows that have been rendered, which are tracked by `measured`
+    let heights: Rc<RefCell<HashMap<K, f64>>> = Default::default();
+    let (measured, set_measured) = create_signal(cx, 0_usize);
+
+    let items = create_memo(cx, move |_| each());
+
+    // the edges of every row only change when the items change or a row is measured,
+    // so scrolling just has to search them
+    let edges = create_memo(cx, {
+        let key = Rc::clone(&key);
+        let heights = Rc::clone(&heights);
+        move |_| {
+            measured.with(|_| ());
+            items.with(|items| {
+                let keys = items.iter().map(|item| key(item)).collect::<Vec<_>>();
+                let mut heights = heights.borrow_mut();
+                // forget the heights of rows that are no longer in the list
+                let current = keys.iter().collect::<HashSet<_>>();
+                heights.retain(|key, _| current.contains(key));
+                row_edges(
+                    keys.iter()
+                        .map(|key| heights.get(key).copied().unwrap_or(item_height)),
+                )
+            })
+        }
+    });
+
+    let window = create_memo(cx, move |_| {
+        edges.with(|edges| visible_window(edges, scroll_top.get(), height, overscan))
+    });
+
+    let visible = move || {
+        let window = window.get();
+        items.with(|items| {
+            // when the list shrinks, this can run before the window has caught up
+            let end = window.end.min(items.len());
+            let start = window.start.min(end);
+            items[start..end].to_vec()
+        })
+    };
+
+    let rows = map_keyed(
+        cx,
+        visible,
+        {
+            let key = Rc::clone(&key);
+            move |cx, item| {
+                let row = render_row(cx, into_row(cx, map_fn(cx, item)));
+                measure_row(cx, &row, key(item), &heights, set_measured);
+                row
+            }
+        },
+        move |item| key(item),
+    );
+
+    render_list(cx, height, window, rows, set_scroll_top)
+}
+
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+thread_local! {
+    static ROW_TEMPLATE: leptos_dom::web_sys::HtmlTemplateElement =
+        leptos_dom::create_template("<div></div>");
+    static LIST_TEMPLATE: leptos_dom::web_sys::HtmlTemplateElement =
+        leptos_dom::create_template("<div><div><div></div></div></div>");
+}
+
+/// Takes the element rendered on the server for the next hydration key, or clones the template.
+#[cfg(feature = "hydrate")]
+fn next_element(cx: Scope, template: &leptos_dom::web_sys::HtmlTemplateElement) -> Element {
+    use leptos_dom::wasm_bindgen::JsCast;
+
+    cx.get_next_element(template.unchecked_ref())
+}
+
+#[cfg(all(feature = "csr", not(feature = "hydrate")))]
+fn next_element(_cx: Scope, template: &leptos_dom::web_sys::HtmlTemplateElement) -> Element {
+    leptos_dom::clone_template(template)
+}
+
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+fn render_row(cx: Scope, child: Child) -> Element {
+    use leptos_dom::{insert, wasm_bindgen::JsCast, Marker};
+
+    let row = ROW_TEMPLATE.with(|template| next_element(cx, template));
+    insert(cx, row.clone().unchecked_into(), child, Marker::NoChildren, None);
+    row
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+fn render_row(cx: Scope, child: Child) -> Element {
+    format!(
+        r#"<div data-hk="{}">{}</div>"#,
+        cx.next_hydration_key(),
+        child.as_child_string()
+    )
+}
+
+/// Measures a row once it has been laid out, and again whenever it's resized, and stores its height
+/// if it has changed.
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+fn measure_row<K>(
+    cx: Scope,
+    row: &Element,
+    key: K,
+    heights: &Rc<RefCell<HashMap<K, f64>>>,
+    set_measured: WriteSignal<usize>,
+) where
+    K: Eq + Hash + Clone + 'static,
+{
+    use leptos_dom::{
+        wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt},
+        web_sys::{HtmlElement, ResizeObserver},
+    };
+    use leptos_reactive::{on_cleanup, queue_microtask};
+
+    let measure = {
+        let row = row.clone();
+        let heights = Rc::clone(heights);
+        move |_entries: JsValue, _observer: JsValue| {
+            let row_height = row.unchecked_ref::<HtmlElement>().offset_height() as f64;
+            let changed = heights.borrow().get(&key) != Some(&row_height);
+            if row_height > 0.0 && changed {
+                heights.borrow_mut().insert(key.clone(), row_height);
+                set_measured.update(|n| *n += 1);
+            }
+        }
+    };
+
+    // the observer calls this once the row has been laid out, and whenever its size changes
+    let measure = Closure::wrap(Box::new(measure) as Box<dyn FnMut(JsValue, JsValue)>);
+    let observer = ResizeObserver::new(measure.as_ref().unchecked_ref()).unwrap_throw();
+    observer.observe(row);
+    on_cleanup(cx, move || {
+        observer.disconnect();
+        queue_microtask(move || drop(measure));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+fn measure_row<K>(
+    _cx: Scope,
+    _row: &Element,
+    _key: K,
+    _heights: &Rc<RefCell<HashMap<K, f64>>>,
+    _set_measured: WriteSignal<usize>,
+) {
+}
+
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+fn render_list(
+    cx: Scope,
+    height: f64,
+    window: Memo<Window>,
+    rows: Memo<Vec<Element>>,
+    set_scroll_top: WriteSignal<f64>,
+) -> Element {
+    use leptos_dom::{
+        add_event_listener_typed, attribute, ev, event_target, insert, set_attribute,
+        wasm_bindgen::JsCast, IntoAttribute, Marker, UnwrapThrowExt,
+    };
+
+    let container = LIST_TEMPLATE.with(|template| next_element(cx, template));
+    set_attribute(
+        &container,
+        "style",
+        &format!("height: {height}px; overflow-y: auto;"),
+    );
+    add_event_listener_typed(cx, &container, ev::scroll, move |ev| {
+        let container = event_target::<Element>(&ev);
+        set_scroll_top.set(container.scroll_top() as f64);
+    });
+
+    let spacer = container.first_element_child().unwrap_throw();
+    attribute(
+        cx,
+        &spacer,
+        "style",
+        (move || format!("position: relative; height: {}px;", window.get().total_height))
+            .into_attribute(cx),
+    );
+
+    let content = spacer.first_element_child().unwrap_throw();
+    attribute(
+        cx,
+        &content,
+        "style",
+        (move || {
+            format!(
+                "position: absolute; top: {}px; left: 0; right: 0;",
+                window.get().offset
+            )
+        })
+        .into_attribute(cx),
+    );
+    // rows rendered on the server have already been hydrated in place
+    let initial = (content.child_element_count() > 0).then(|| rows.get().into_child(cx));
+    insert(
+        cx,
+        content.clone().unchecked_into(),
+        (move || rows.get()).into_child(cx),
+        Marker::NoChildren,
+        initial,
+    );
+
+    container
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+fn render_list(
+    cx: Scope,
+    height: f64,
+    window: Memo<Window>,
+    rows: Memo<Vec<Element>>,
+    _set_scroll_top: WriteSignal<f64>,
+) -> Element {
+    let window = window.get();
+    format!(
+        r#"<div data-hk="{}" style="height: {height}px; overflow-y: auto;"><div style="position: relative; height: {}px;"><div style="position: absolute; top: {}px; left: 0; right: 0;">{}</div></div></div>"#,
+        cx.next_hydration_key(),
+        window.total_height,
+        window.offset,
+        rows.with(|rows| rows.concat())
+    )
+}
+
+/// The range of rows that should be rendered, and where they should be placed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Window {
+    start: usize,
+    end: usize,
+    /// Distance from the top of the list to the first rendered row.
+    offset: f64,
+    total_height: f64,
+}
+
+/// Returns the edges of the rows with the given heights: `edges[i]` is the top of row `i`, and
+/// `edges[i + 1]` its bottom.
+fn row_edges(heights: impl IntoIterator<Item = f64>) -> Vec<f64> {
+    let mut top = 0.0;
+    let mut edges = vec![top];
+    for height in heights {
+        top += height;
+        edges.push(top);
+    }
+    edges
+}
+
+fn visible_window(edges: &[f64], scroll_top: f64, viewport_height: f64, overscan: usize) -> Window {
+    let len = edges.len() - 1;
+
+    // first row whose bottom is below the top of the viewport
+    let first = edges[1..].partition_point(|bottom| *bottom <= scroll_top);
+    // first row whose top is at or below the bottom of the viewport
+    let last = edges[..len].partition_point(|top| *top < scroll_top + viewport_height);
+
+    let start = first.saturating_sub(overscan);
+    let end = (last + overscan).min(len).max(start);
+    Window {
+        start,
+        end,
+        offset: edges[start],
+        total_height: edges[len],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{row_edges, visible_window, Window};
+
+    #[test]
+    fn visible_window_includes_overscan() {
+        let edges = row_edges(vec![10.0; 100]);
+        assert_eq!(
+            visible_window(&edges, 0.0, 50.0, 2),
+            Window {
+                start: 0,
+                end: 7,
+                offset: 0.0,
+                total_height: 1000.0
+            }
+        );
+        assert_eq!(
+            visible_window(&edges, 105.0, 50.0, 2),
+            Window {
+                start: 8,
+                end: 18,
+                offset: 80.0,
+                total_height: 1000.0
+            }
+        );
+        assert_eq!(
+            visible_window(&edges, 990.0, 50.0, 2),
+            Window {
+                start: 97,
+                end: 100,
+                offset: 970.0,
+                total_height: 1000.0
+            }
+        );
+    }
+
+    #[test]
+    fn visible_window_uses_row_heights() {
+        let edges = row_edges(vec![100.0, 10.0, 10.0, 10.0, 100.0, 10.0]);
+        let window = visible_window(&edges, 105.0, 30.0, 0);
+        assert_eq!((window.start, window.end, window.offset), (1, 5, 100.0));
+    }
+}
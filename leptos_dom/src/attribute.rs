@@ -34,6 +34,22 @@ impl Attribute {
             }
         }
     }
+
+    /// The value of the attribute without its name, or `None` if it shouldn't be rendered.
+    pub fn as_nameless_value_string(&self) -> Option<String> {
+        match self {
+            Attribute::String(value) => Some(value.clone()),
+            Attribute::Fn(f) => {
+                let mut value = f();
+                while let Attribute::Fn(f) = value {
+                    value = f();
+                }
+                value.as_nameless_value_string()
+            }
+            Attribute::Option(value) => value.clone(),
+            Attribute::Bool(include) => include.then(String::new),
+        }
+    }
 }
 
 impl PartialEq for Attribute {
@@ -59,6 +75,13 @@ impl std::fmt::Debug for Attribute {
     }
 }
 
+/// A list of named attributes, which can be spread onto an element with `{..attrs}` in the `view` macro.
+///
+/// A component can capture attributes that aren't its own props (any attribute with a dash in its name,
+/// like `aria-label` or `data-id`, and any `{..attrs}` spreads) with a prop named `attrs` of this type,
+/// and spread them onto one of its elements.
+pub type SpreadAttributes = Vec<(&'static str, Attribute)>;
+
 pub trait IntoAttribute {
     fn into_attribute(self, cx: Scope) -> Attribute;
 }
@@ -32,6 +32,33 @@ impl Child {
             Child::Nodes(nodes) => nodes.iter().cloned().collect(),
         }
     }
+
+    /// Flattens the child into a list of nodes, calling any functions it contains. Text is
+    /// rendered into a new text node each time this is called.
+    pub fn flatten_into(self, nodes: &mut Vec<Node>) {
+        match self {
+            Child::Null => {}
+            Child::Text(text) => nodes.push(text_node(text)),
+            Child::Fn(f) => {
+                let value = (f.borrow_mut())();
+                value.flatten_into(nodes);
+            }
+            Child::Node(node) => nodes.push(node),
+            Child::Nodes(mut new_nodes) => nodes.append(&mut new_nodes),
+        }
+    }
+}
+
+#[cfg(not(any(feature = "hydrate", feature = "csr")))]
+fn text_node(text: String) -> Node {
+    text
+}
+
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+fn text_node(text: String) -> Node {
+    use wasm_bindgen::JsCast;
+
+    crate::create_text_node(&text).unchecked_into()
 }
 
 impl std::fmt::Debug for Child {
@@ -109,6 +136,18 @@ impl IntoChild for Vec<Node> {
     }
 }
 
+/// A list of children, each of which may be made of any number of nodes (like the rows of a
+/// `<For/>`), is flattened into a single list of nodes.
+impl IntoChild for Vec<Child> {
+    fn into_child(self, _cx: Scope) -> Child {
+        let mut nodes = Vec::new();
+        for child in self {
+            child.flatten_into(&mut nodes);
+        }
+        Child::Nodes(nodes)
+    }
+}
+
 macro_rules! child_type {
     ($child_type:ty) => {
         impl IntoChild for $child_type {
@@ -175,7 +214,7 @@ cfg_if! {
 // `stable` feature
 cfg_if! {
     if #[cfg(feature = "stable")] {
-        use leptos_reactive::{Memo, ReadSignal, RwSignal};
+        use leptos_reactive::{MaybeSignal, Memo, ReadSignal, RwSignal, Signal};
 
         impl IntoChild for Memo<Vec<crate::Element>> {
             fn into_child(self, cx: Scope) -> Child {
@@ -194,5 +233,29 @@ cfg_if! {
                 (move || self.get()).into_child(cx)
             }
         }
+
+        impl IntoChild for Memo<Vec<Child>> {
+            fn into_child(self, cx: Scope) -> Child {
+                (move || self.get()).into_child(cx)
+            }
+        }
+
+        impl<T> IntoChild for Signal<T>
+        where
+            T: IntoChild + Clone,
+        {
+            fn into_child(self, cx: Scope) -> Child {
+                (move || self.get()).into_child(cx)
+            }
+        }
+
+        impl<T> IntoChild for MaybeSignal<T>
+        where
+            T: IntoChild + Clone,
+        {
+            fn into_child(self, cx: Scope) -> Child {
+                (move || self.get()).into_child(cx)
+            }
+        }
     }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use leptos_reactive::Scope;
 
 pub enum Class {
@@ -46,3 +48,119 @@ impl Class {
         }
     }
 }
+
+/// A list of class names, set with a dynamic `class` attribute in the `view` macro.
+///
+/// When the list changes, only the names that were added or removed are updated, so it
+/// can be used alongside `class:` attributes on the same element.
+pub enum ClassList {
+    Value(Vec<String>),
+    Fn(Box<dyn Fn() -> Vec<String>>),
+}
+
+pub trait IntoClassList {
+    fn into_class_list(self, cx: Scope) -> ClassList;
+}
+
+impl<T, U> IntoClassList for T
+where
+    T: Fn() -> U + 'static,
+    U: IntoClassList,
+{
+    fn into_class_list(self, cx: Scope) -> ClassList {
+        let modified_fn = Box::new(move || (self)().into_class_list(cx).names());
+        ClassList::Fn(modified_fn)
+    }
+}
+
+// each item may itself contain several whitespace-separated class names
+fn split_class_names(names: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<String> {
+    let mut split = Vec::new();
+    for names in names {
+        for name in names.as_ref().split_whitespace() {
+            let name = name.to_string();
+            if !split.contains(&name) {
+                split.push(name);
+            }
+        }
+    }
+    split
+}
+
+macro_rules! class_list_type {
+    (single: $($class_list_type:ty),*) => {
+        $(
+            impl IntoClassList for $class_list_type {
+                fn into_class_list(self, _cx: Scope) -> ClassList {
+                    ClassList::Value(split_class_names(std::iter::once(self)))
+                }
+            }
+        )*
+    };
+    (many: $($class_list_type:ty),*) => {
+        $(
+            impl IntoClassList for $class_list_type {
+                fn into_class_list(self, _cx: Scope) -> ClassList {
+                    ClassList::Value(split_class_names(self))
+                }
+            }
+        )*
+    };
+    // sorted, so that the order of the names doesn't depend on the order of iteration
+    (unordered: $($class_list_type:ty),*) => {
+        $(
+            impl IntoClassList for $class_list_type {
+                fn into_class_list(self, _cx: Scope) -> ClassList {
+                    let mut names = split_class_names(self);
+                    names.sort();
+                    ClassList::Value(names)
+                }
+            }
+        )*
+    };
+    (toggled: $($class_list_type:ty),*) => {
+        $(
+            impl IntoClassList for $class_list_type {
+                fn into_class_list(self, _cx: Scope) -> ClassList {
+                    ClassList::Value(split_class_names(
+                        self.into_iter().filter_map(|(name, on)| on.then_some(name)),
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+class_list_type!(single: String, &String, &str);
+class_list_type!(many: Option<String>, Option<&str>, Vec<String>, Vec<&str>);
+class_list_type!(unordered: HashSet<String>, HashSet<&str>);
+class_list_type!(toggled: Vec<(String, bool)>, Vec<(&str, bool)>);
+
+impl<const N: usize> IntoClassList for [&str; N] {
+    fn into_class_list(self, _cx: Scope) -> ClassList {
+        ClassList::Value(split_class_names(self))
+    }
+}
+
+impl<const N: usize> IntoClassList for [(&str, bool); N] {
+    fn into_class_list(self, _cx: Scope) -> ClassList {
+        ClassList::Value(split_class_names(
+            self.into_iter().filter_map(|(name, on)| on.then_some(name)),
+        ))
+    }
+}
+
+impl ClassList {
+    /// The class names currently in the list.
+    pub fn names(&self) -> Vec<String> {
+        match self {
+            ClassList::Value(names) => names.clone(),
+            ClassList::Fn(f) => f(),
+        }
+    }
+
+    /// Renders the class names separated by spaces.
+    pub fn as_value_string(&self) -> String {
+        self.names().join(" ")
+    }
+}
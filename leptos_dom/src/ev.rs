@@ -0,0 +1,215 @@
+//! Types for the DOM events that can be handled with `on:` in the `view` macro.
+//!
+//! Each event knows its name and the [web_sys] type of the event object its handlers
+//! receive, so that `on:click` handlers are passed a [web_sys::MouseEvent],
+//! `on:keydown` handlers a [web_sys::KeyboardEvent], and so on.
+
+/// A DOM event, which knows its name and the type of event object its handlers receive.
+pub trait EventDescriptor: Copy + 'static {
+    /// The [web_sys] type of the event object passed to handlers.
+    type EventType: wasm_bindgen::JsCast;
+
+    /// The name of the event, like `"click"`.
+    fn name(&self) -> &'static str;
+
+    /// Whether the event bubbles, which means it can be handled using event delegation.
+    fn bubbles(&self) -> bool {
+        true
+    }
+}
+
+/// An event that isn't listed in this module. Its handlers receive a plain [web_sys::Event].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Custom {
+    name: &'static str,
+    bubbles: bool,
+}
+
+impl Custom {
+    /// Describes an event with the given name, which is assumed to bubble.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            bubbles: true,
+        }
+    }
+
+    /// Describes an event with the given name that does not bubble.
+    pub fn non_bubbling(name: &'static str) -> Self {
+        Self {
+            name,
+            bubbles: false,
+        }
+    }
+}
+
+impl EventDescriptor for Custom {
+    type EventType = web_sys::Event;
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn bubbles(&self) -> bool {
+        self.bubbles
+    }
+}
+
+/// Modifiers that change how an event listener is called, which are added to `on:` in the `view`
+/// macro, like `on:submit:prevent` or `on:keydown:enter`.
+///
+/// Listeners that are `passive` or `capture` are added directly to the element, rather than
+/// using event delegation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventModifiers {
+    /// Calls `preventDefault()` on the event before the handler runs (`:prevent`).
+    pub prevent_default: bool,
+    /// Calls `stopPropagation()` on the event before the handler runs (`:stop`).
+    pub stop_propagation: bool,
+    /// Only runs the handler the first time the event fires (`:once`).
+    pub once: bool,
+    /// Promises the browser that the handler won't call `preventDefault()` (`:passive`).
+    pub passive: bool,
+    /// Runs the handler during the capture phase, before the event reaches its target (`:capture`).
+    pub capture: bool,
+    /// Only runs the handler for keyboard events whose `key` is this value (`:enter`, `:escape`, etc.).
+    pub key: Option<&'static str>,
+}
+
+impl EventModifiers {
+    /// Whether the listener needs to be added to the element itself, rather than delegated.
+    pub(crate) fn needs_native_listener(&self) -> bool {
+        self.passive || self.capture
+    }
+
+    /// Whether the handler should run for this event.
+    pub(crate) fn matches(&self, ev: &web_sys::Event) -> bool {
+        match self.key {
+            Some(key) => {
+                use wasm_bindgen::JsCast;
+                ev.dyn_ref::<web_sys::KeyboardEvent>()
+                    .map(|ev| ev.key() == key)
+                    .unwrap_or(false)
+            }
+            None => true,
+        }
+    }
+
+    /// Applies the `prevent` and `stop` modifiers to the event.
+    pub(crate) fn apply(&self, ev: &web_sys::Event) {
+        if self.prevent_default {
+            ev.prevent_default();
+        }
+        if self.stop_propagation {
+            ev.stop_propagation();
+        }
+    }
+}
+
+macro_rules! generate_event_types {
+    ($bubbles:literal: $($event:ident: $web_sys_event:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("The `", stringify!($event), "` event, whose handlers receive a [web_sys::", stringify!($web_sys_event), "].")]
+            #[allow(non_camel_case_types)]
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            pub struct $event;
+
+            impl EventDescriptor for $event {
+                type EventType = web_sys::$web_sys_event;
+
+                fn name(&self) -> &'static str {
+                    stringify!($event)
+                }
+
+                fn bubbles(&self) -> bool {
+                    $bubbles
+                }
+            }
+        )*
+    };
+}
+
+generate_event_types!(true:
+    // mouse
+    auxclick: MouseEvent,
+    click: MouseEvent,
+    contextmenu: MouseEvent,
+    dblclick: MouseEvent,
+    mousedown: MouseEvent,
+    mousemove: MouseEvent,
+    mouseout: MouseEvent,
+    mouseover: MouseEvent,
+    mouseup: MouseEvent,
+    // pointer
+    gotpointercapture: PointerEvent,
+    lostpointercapture: PointerEvent,
+    pointercancel: PointerEvent,
+    pointerdown: PointerEvent,
+    pointermove: PointerEvent,
+    pointerout: PointerEvent,
+    pointerover: PointerEvent,
+    pointerup: PointerEvent,
+    // wheel
+    wheel: WheelEvent,
+    // keyboard
+    keydown: KeyboardEvent,
+    keypress: KeyboardEvent,
+    keyup: KeyboardEvent,
+    // focus
+    focusin: FocusEvent,
+    focusout: FocusEvent,
+    // forms
+    beforeinput: InputEvent,
+    change: Event,
+    input: Event,
+    invalid: Event,
+    reset: Event,
+    select: Event,
+    submit: SubmitEvent,
+    // touch
+    touchcancel: TouchEvent,
+    touchend: TouchEvent,
+    touchmove: TouchEvent,
+    touchstart: TouchEvent,
+    // drag and drop
+    drag: DragEvent,
+    dragend: DragEvent,
+    dragenter: DragEvent,
+    dragleave: DragEvent,
+    dragover: DragEvent,
+    dragstart: DragEvent,
+    drop: DragEvent,
+    // clipboard
+    copy: ClipboardEvent,
+    cut: ClipboardEvent,
+    paste: ClipboardEvent,
+    // composition
+    compositionend: CompositionEvent,
+    compositionstart: CompositionEvent,
+    compositionupdate: CompositionEvent,
+    // animations and transitions
+    animationend: AnimationEvent,
+    animationiteration: AnimationEvent,
+    animationstart: AnimationEvent,
+    transitioncancel: TransitionEvent,
+    transitionend: TransitionEvent,
+    transitionrun: TransitionEvent,
+    transitionstart: TransitionEvent,
+);
+
+generate_event_types!(false:
+    abort: UiEvent,
+    blur: FocusEvent,
+    error: Event,
+    focus: FocusEvent,
+    load: Event,
+    loadend: ProgressEvent,
+    loadstart: ProgressEvent,
+    mouseenter: MouseEvent,
+    mouseleave: MouseEvent,
+    pointerenter: PointerEvent,
+    pointerleave: PointerEvent,
+    progress: ProgressEvent,
+    scroll: Event,
+    unload: Event,
+);
@@ -1,12 +1,47 @@
 use std::cell::RefCell;
 use std::collections::HashSet;
 
-use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+use leptos_reactive::{on_cleanup, queue_microtask, Scope};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
 
-use crate::window_event_listener;
+use crate::window;
 
 thread_local! {
     pub static GLOBAL_EVENTS: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    // shadow roots that views have been mounted into, which need their own handler for each event
+    static SHADOW_ROOTS: RefCell<Vec<ShadowRootHandlers>> = const { RefCell::new(Vec::new()) };
+}
+
+// the delegated handlers added to a shadow root, which are removed once nothing is mounted in it
+struct ShadowRootHandlers {
+    root: web_sys::ShadowRoot,
+    handlers: Vec<(&'static str, Closure<dyn FnMut(web_sys::Event)>)>,
+    // how many scopes have mounted views into the root
+    mounts: usize,
+}
+
+impl ShadowRootHandlers {
+    fn add(&mut self, event_name: &'static str) {
+        let key = JsValue::from_str(&event_delegation_key(event_name));
+        let handler = move |ev: web_sys::Event| handle_event(ev, &key);
+        let handler = Closure::wrap(Box::new(handler) as Box<dyn FnMut(web_sys::Event)>);
+        _ = self
+            .root
+            .add_event_listener_with_callback(event_name, handler.as_ref().unchecked_ref());
+        self.handlers.push((event_name, handler));
+    }
+}
+
+impl Drop for ShadowRootHandlers {
+    fn drop(&mut self) {
+        for (event_name, handler) in std::mem::take(&mut self.handlers) {
+            _ = self
+                .root
+                .remove_event_listener_with_callback(event_name, handler.as_ref().unchecked_ref());
+            // the root may be unmounted by one of these handlers, which is still running
+            queue_microtask(move || drop(handler));
+        }
+    }
 }
 
 // cf eventHandler in ryansolid/dom-expressions
@@ -16,65 +51,162 @@ pub(crate) fn add_event_listener(event_name: &'static str) {
         if !events.contains(event_name) {
             // create global handler
             let key = JsValue::from_str(&event_delegation_key(event_name));
-            let handler = move |ev: web_sys::Event| {
-                let target = ev.target();
-                let node = ev.composed_path().get(0);
-                let mut node = if node.is_undefined() || node.is_null() {
-                    JsValue::from(target)
-                } else {
-                    node
-                };
+            let handler = move |ev: web_sys::Event| handle_event(ev, &key);
+
+            // the global handler lives as long as the page does
+            let handler = Closure::wrap(Box::new(handler) as Box<dyn FnMut(web_sys::Event)>)
+                .into_js_value();
+            _ = window().add_event_listener_with_callback(event_name, handler.unchecked_ref());
+            SHADOW_ROOTS.with(|roots| {
+                for root in roots.borrow_mut().iter_mut() {
+                    root.add(event_name);
+                }
+            });
 
-                // TODO reverse Shadow DOM retargetting
-
-                // TODO simulate currentTarget
-
-                while !node.is_null() {
-                    let node_is_disabled =
-                        js_sys::Reflect::get(&node, &JsValue::from_str("disabled"))
-                            .unwrap_throw()
-                            .is_truthy();
-                    if !node_is_disabled {
-                        let maybe_handler = js_sys::Reflect::get(&node, &key).unwrap_throw();
-                        if !maybe_handler.is_undefined() {
-                            let f = maybe_handler.unchecked_ref::<js_sys::Function>();
-                            if let Err(e) = f.call1(&node, &ev) {
-                                crate::debug_warn!("{e:#?}");
-
-                                #[cfg(not(debug_assertions))]
-                                {
-                                    _ = e;
-                                }
-                            }
-
-                            if ev.cancel_bubble() {
-                                return;
-                            }
-                        }
+            // register that we've created handler
+            events.insert(event_name);
+        }
+    })
+}
+
+/// If `node` is inside a shadow root, delegates events inside that root to it: events that aren't
+/// `composed` never leave the shadow root they were dispatched in, so they never reach the handlers
+/// on the `window`. This covers events that have already been delegated and any delegated later.
+/// The handlers are removed once every [Scope] that has mounted into the root has been cleaned up.
+///
+/// Call this after mounting a view into a shadow root. [mount](crate::mount) and `<Portal/>` call
+/// it for the element they mount into.
+pub fn delegate_shadow_root_events(cx: Scope, node: &web_sys::Node) {
+    let root = match node.get_root_node().dyn_into::<web_sys::ShadowRoot>() {
+        Ok(root) => root,
+        Err(_) => return,
+    };
+    SHADOW_ROOTS.with(|roots| {
+        let mut roots = roots.borrow_mut();
+        match roots.iter_mut().find(|handlers| handlers.root == root) {
+            Some(handlers) => handlers.mounts += 1,
+            None => {
+                let mut handlers = ShadowRootHandlers {
+                    root: root.clone(),
+                    handlers: Vec::new(),
+                    mounts: 1,
+                };
+                GLOBAL_EVENTS.with(|events| {
+                    for event_name in events.borrow().iter() {
+                        handlers.add(event_name);
                     }
+                });
+                roots.push(handlers);
+            }
+        }
+    });
 
-                    // navigate up tree
-                    let host =
-                        js_sys::Reflect::get(&node, &JsValue::from_str("host")).unwrap_throw();
-                    if host.is_truthy() && host != node && host.dyn_ref::<web_sys::Node>().is_some()
-                    {
-                        node = host;
-                    } else if let Some(parent) =
-                        node.unchecked_into::<web_sys::Node>().parent_node()
+    on_cleanup(cx, move || {
+        let removed = SHADOW_ROOTS.with(|roots| {
+            let mut roots = roots.borrow_mut();
+            let index = roots.iter().position(|handlers| handlers.root == root)?;
+            roots[index].mounts -= 1;
+            (roots[index].mounts == 0).then(|| roots.swap_remove(index))
+        });
+        // drop it once SHADOW_ROOTS is no longer borrowed
+        drop(removed);
+    });
+}
+
+fn handle_event(ev: web_sys::Event, key: &JsValue) {
+    // an event from inside a shadow root may reach a shadow root handler and the window;
+    // it should only be delegated once
+    let delegated = JsValue::from_str("$$$delegated");
+    if js_sys::Reflect::get(&ev, &delegated)
+        .unwrap_throw()
+        .is_truthy()
+    {
+        return;
+    }
+    _ = js_sys::Reflect::set(&ev, &delegated, &JsValue::TRUE);
+
+    let target = JsValue::from(ev.target());
+    let node = ev.composed_path().get(0);
+    let node = if node.is_undefined() || node.is_null() {
+        target.clone()
+    } else {
+        node
+    };
+
+    // reverse Shadow DOM retargetting
+    let retargeted = node != target;
+    if retargeted {
+        define_event_property(&ev, "target", &node);
+    }
+
+    dispatch(&ev, key, node);
+
+    // listeners outside the delegated handlers (like those on a shadow root's host) should see
+    // the event's own properties again
+    restore_event_property(&ev, "currentTarget");
+    if retargeted {
+        restore_event_property(&ev, "target");
+    }
+}
+
+// calls the handlers from `node` up to the root, or until one of them stops propagation
+fn dispatch(ev: &web_sys::Event, key: &JsValue, mut node: JsValue) {
+    while !node.is_null() {
+        let node_is_disabled = js_sys::Reflect::get(&node, &JsValue::from_str("disabled"))
+            .unwrap_throw()
+            .is_truthy();
+        if !node_is_disabled {
+            let maybe_handler = js_sys::Reflect::get(&node, key).unwrap_throw();
+            if !maybe_handler.is_undefined() {
+                // simulate currentTarget
+                define_event_property(ev, "currentTarget", &node);
+
+                let f = maybe_handler.unchecked_ref::<js_sys::Function>();
+                if let Err(e) = f.call1(&node, ev) {
+                    crate::debug_warn!("{e:#?}");
+
+                    #[cfg(not(debug_assertions))]
                     {
-                        node = parent.into()
-                    } else {
-                        node = JsValue::null()
+                        _ = e;
                     }
                 }
-            };
 
-            window_event_listener(event_name, handler);
+                if ev.cancel_bubble() {
+                    return;
+                }
+            }
+        }
 
-            // register that we've created handler
-            events.insert(event_name);
+        // navigate up tree
+        let host = js_sys::Reflect::get(&node, &JsValue::from_str("host")).unwrap_throw();
+        if host.is_truthy() && host != node && host.dyn_ref::<web_sys::Node>().is_some() {
+            node = host;
+        } else if let Some(parent) = node.unchecked_into::<web_sys::Node>().parent_node() {
+            node = parent.into()
+        } else {
+            node = JsValue::null()
         }
-    })
+    }
+}
+
+// shadows one of the event's own (read-only) properties with the given value
+fn define_event_property(ev: &web_sys::Event, name: &str, value: &JsValue) {
+    let descriptor = js_sys::Object::new();
+    _ = js_sys::Reflect::set(&descriptor, &JsValue::from_str("configurable"), &JsValue::TRUE);
+    _ = js_sys::Reflect::set(&descriptor, &JsValue::from_str("value"), value);
+    js_sys::Object::define_property(
+        ev.unchecked_ref::<js_sys::Object>(),
+        &JsValue::from_str(name),
+        &descriptor,
+    );
+}
+
+// removes a property added by `define_event_property`, so the event's own getter is used again
+fn restore_event_property(ev: &web_sys::Event, name: &str) {
+    _ = js_sys::Reflect::delete_property(
+        ev.unchecked_ref::<js_sys::Object>(),
+        &JsValue::from_str(name),
+    );
 }
 
 pub(crate) fn event_delegation_key(event_name: &'static str) -> String {
@@ -3,11 +3,14 @@ use cfg_if::cfg_if;
 pub mod attribute;
 pub mod child;
 pub mod class;
+pub mod ev;
 pub mod event_delegation;
 pub mod logging;
 pub mod mount;
+pub mod node_ref;
 pub mod operations;
 pub mod property;
+pub mod style;
 
 cfg_if! {
     // can only include this if we're *only* enabling SSR, as it's the lowest-priority feature
@@ -37,8 +40,10 @@ pub use child::*;
 pub use class::*;
 pub use logging::*;
 pub use mount::*;
+pub use node_ref::*;
 pub use operations::*;
 pub use property::*;
+pub use style::*;
 
 pub use js_sys;
 pub use wasm_bindgen;
@@ -50,6 +50,8 @@ where
     // as the "mount" has no parent that can clean it up
     let _ = create_scope(move |cx| {
         (f(cx)).mount(&parent);
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        crate::event_delegation::delegate_shadow_root_events(cx, &parent);
     });
 }
 
@@ -65,5 +67,6 @@ where
         cx.start_hydration(&parent);
         (f(cx));
         cx.end_hydration();
+        crate::event_delegation::delegate_shadow_root_events(cx, &parent);
     });
 }
@@ -0,0 +1,83 @@
+use std::cell::Cell;
+
+use leptos_reactive::{create_effect, create_rw_signal, queue_microtask, RwSignal, Scope};
+use wasm_bindgen::JsCast;
+
+/// Contains a shared reference to a DOM node created while using the `view`
+/// macro to create your UI.
+///
+/// The reference is empty until the element has been created and mounted, and is always
+/// empty on the server. Reading it inside an effect will re-run the effect once the element loads.
+///
+/// ```
+/// # use leptos_reactive::*; use leptos_dom::*;
+/// # run_scope(|cx| {
+/// let input_ref = create_node_ref::<web_sys::HtmlInputElement>(cx);
+///
+/// // focus the input as soon as it's mounted
+/// input_ref.on_load(cx, |input| {
+///     _ = input.focus();
+/// });
+///
+/// // nothing has been loaded yet
+/// assert!(input_ref.get().is_none());
+/// # });
+/// ```
+pub struct NodeRef<T: JsCast + Clone + 'static>(RwSignal<Option<T>>);
+
+/// Creates an empty reference to a DOM node, which can be filled by passing it
+/// as the `ref` attribute of an element in the `view` macro.
+pub fn create_node_ref<T: JsCast + Clone + 'static>(cx: Scope) -> NodeRef<T> {
+    NodeRef(create_rw_signal(cx, None))
+}
+
+impl<T: JsCast + Clone + 'static> NodeRef<T> {
+    /// Gets the element that is currently stored in the reference.
+    ///
+    /// This tracks reactively, so an effect that calls it will run again when the element loads.
+    pub fn get(&self) -> Option<T> {
+        self.0.get()
+    }
+
+    /// Runs the given function once, when the element has loaded. Never runs on the server.
+    pub fn on_load(self, cx: Scope, f: impl FnOnce(T) + 'static) {
+        let f = Cell::new(Some(f));
+        create_effect(cx, move |_| {
+            if let Some(node) = self.get() {
+                if let Some(f) = f.take() {
+                    f(node);
+                }
+            }
+        });
+    }
+
+    /// Loads the element into the reference. This is called by the `view` macro.
+    #[doc(hidden)]
+    pub fn load(&self, node: &web_sys::Element) {
+        match node.clone().dyn_into::<T>() {
+            Ok(node) => {
+                // wait until the element has been mounted before notifying anyone
+                let signal = self.0;
+                queue_microtask(move || signal.set(Some(node)));
+            }
+            Err(_) => crate::debug_warn!(
+                "[NodeRef] the element with a `ref` was not the type the NodeRef expected: {}",
+                std::any::type_name::<T>()
+            ),
+        }
+    }
+}
+
+impl<T: JsCast + Clone + 'static> Clone for NodeRef<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: JsCast + Clone + 'static> Copy for NodeRef<T> {}
+
+impl<T: JsCast + Clone + std::fmt::Debug + 'static> std::fmt::Debug for NodeRef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NodeRef").field(&self.0).finish()
+    }
+}
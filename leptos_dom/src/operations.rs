@@ -1,8 +1,9 @@
-use std::time::Duration;
+use std::{cell::RefCell, rc::Rc, time::Duration};
 
+use leptos_reactive::{on_cleanup, queue_microtask, Scope};
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
 
-use crate::{debug_warn, event_delegation, is_server};
+use crate::{debug_warn, ev::{EventDescriptor, EventModifiers}, event_delegation, is_server};
 
 thread_local! {
     pub static WINDOW: web_sys::Window = web_sys::window().unwrap_throw();
@@ -22,10 +23,24 @@ pub fn body() -> Option<web_sys::HtmlElement> {
     document().body()
 }
 
+/// The namespace of SVG elements.
+pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// The namespace of MathML elements.
+pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// Creates an HTML element. SVG and MathML elements are created with [create_element_ns].
 pub fn create_element(tag_name: &str) -> web_sys::Element {
     document().create_element(tag_name).unwrap_throw()
 }
 
+/// Creates an element in the given namespace, like [SVG_NAMESPACE].
+pub fn create_element_ns(namespace: &str, tag_name: &str) -> web_sys::Element {
+    document()
+        .create_element_ns(Some(namespace), tag_name)
+        .unwrap_throw()
+}
+
 pub fn create_text_node(data: &str) -> web_sys::Text {
     document().create_text_node(data)
 }
@@ -44,6 +59,21 @@ pub fn create_template(html: &str) -> web_sys::HtmlTemplateElement {
     template.unchecked_into()
 }
 
+/// Creates a template whose root is an SVG or MathML element, like `<circle>` or `<mi>`. The HTML is
+/// parsed inside the given wrapper (`svg` or `math`) so that its elements get the right namespace,
+/// and the wrapper is then removed, so the template can be cloned like any other. The `view` macro
+/// uses this for views whose root is in one of those namespaces.
+pub fn create_namespaced_template(wrapper: &str, html: &str) -> web_sys::HtmlTemplateElement {
+    let template = create_template(&format!("<{wrapper}>{html}</{wrapper}>"));
+    let content = template.content();
+    let wrapper = content.first_element_child().unwrap_throw();
+    while let Some(child) = wrapper.first_child() {
+        content.insert_before(&child, Some(&wrapper)).unwrap_throw();
+    }
+    wrapper.remove();
+    template
+}
+
 pub fn clone_template(template: &web_sys::HtmlTemplateElement) -> web_sys::Element {
     template
         .content()
@@ -234,13 +264,86 @@ pub fn add_event_listener(
     event_delegation::add_event_listener(event_name);
 }
 
+/// Adds an event listener directly to the target DOM element, without event delegation.
+/// The listener is removed when `cx` is cleaned up.
 pub fn add_event_listener_undelegated(
+    cx: Scope,
     target: &web_sys::Element,
     event_name: &'static str,
     cb: impl FnMut(web_sys::Event) + 'static,
 ) {
-    let cb = Closure::wrap(Box::new(cb) as Box<dyn FnMut(web_sys::Event)>).into_js_value();
-    _ = target.add_event_listener_with_callback(event_name, cb.unchecked_ref());
+    add_scoped_event_listener(cx, target, event_name, EventModifiers::default(), Box::new(cb));
+}
+
+/// Adds an event listener for one of the events described in [ev](crate::ev), using event
+/// delegation if the event bubbles. The handler receives the event's specific type, like a
+/// [web_sys::MouseEvent] for [ev::click](crate::ev::click).
+pub fn add_event_listener_typed<E: EventDescriptor>(
+    cx: Scope,
+    target: &web_sys::Element,
+    event: E,
+    mut cb: impl FnMut(E::EventType) + 'static,
+) {
+    let cb = move |ev: web_sys::Event| cb(ev.unchecked_into::<E::EventType>());
+    if event.bubbles() {
+        add_event_listener(target, event.name(), cb);
+    } else {
+        add_event_listener_undelegated(cx, target, event.name(), cb);
+    }
+}
+
+/// Adds an event listener with [EventModifiers], as created by `on:click:prevent` and similar in the
+/// `view` macro. The listener is delegated unless the event doesn't bubble or the modifiers include
+/// `passive` or `capture`.
+pub fn add_event_listener_with_modifiers<E: EventDescriptor>(
+    cx: Scope,
+    target: &web_sys::Element,
+    event: E,
+    modifiers: EventModifiers,
+    cb: impl FnMut(E::EventType) + 'static,
+) {
+    let cb = RefCell::new(Some(cb));
+    let native = !event.bubbles() || modifiers.needs_native_listener();
+    let delegated_target = (!native).then(|| target.clone());
+    let event_name = event.name();
+    #[allow(clippy::type_complexity)]
+    let remove_native: Rc<RefCell<Option<Rc<dyn Fn()>>>> = Default::default();
+    let cb = {
+        let remove_native = Rc::clone(&remove_native);
+        move |ev: web_sys::Event| {
+            if !modifiers.matches(&ev) {
+                return;
+            }
+            modifiers.apply(&ev);
+            let mut cb_ref = cb.borrow_mut();
+            if let Some(f) = cb_ref.as_mut() {
+                f(ev.unchecked_into::<E::EventType>());
+            }
+            if modifiers.once {
+                // drop the handler, and stop the delegated handler from finding it again
+                *cb_ref = None;
+                if let Some(target) = &delegated_target {
+                    let key = event_delegation::event_delegation_key(event_name);
+                    _ = js_sys::Reflect::delete_property(target, &JsValue::from_str(&key));
+                }
+                // remove a native listener too
+                if let Some(remove) = remove_native.borrow_mut().take() {
+                    remove();
+                }
+            }
+        }
+    };
+    if native {
+        *remove_native.borrow_mut() = Some(add_scoped_event_listener(
+            cx,
+            target,
+            event_name,
+            modifiers,
+            Box::new(cb),
+        ));
+    } else {
+        add_event_listener(target, event_name, cb);
+    }
 }
 
 #[inline(always)]
@@ -248,15 +351,92 @@ pub fn ssr_event_listener(_cb: impl FnMut(web_sys::Event) + 'static) {
     // this function exists only for type inference in templates for SSR
 }
 
-pub fn window_event_listener(event_name: &str, cb: impl Fn(web_sys::Event) + 'static) {
+#[inline(always)]
+pub fn ssr_event_listener_typed<E: EventDescriptor>(_event: E, _cb: impl FnMut(E::EventType) + 'static) {
+    // this function exists only for type inference in templates for SSR
+}
+
+/// Adds an event listener to the `window`, which is removed when the reactive [Scope] is disposed,
+/// or when you call [WindowListenerHandle::remove] on the handle it returns.
+pub fn window_event_listener(
+    cx: Scope,
+    event_name: &str,
+    cb: impl Fn(web_sys::Event) + 'static,
+) -> WindowListenerHandle {
     if !is_server!() {
-        let handler = Box::new(cb) as Box<dyn FnMut(web_sys::Event)>;
+        WindowListenerHandle(Some(add_scoped_event_listener(
+            cx,
+            &window(),
+            event_name,
+            EventModifiers::default(),
+            Box::new(cb),
+        )))
+    } else {
+        WindowListenerHandle(None)
+    }
+}
 
-        let cb = Closure::wrap(handler).into_js_value();
-        _ = window().add_event_listener_with_callback(event_name, cb.unchecked_ref());
+/// A handle to an event listener on the `window`, created by [window_event_listener].
+pub struct WindowListenerHandle(Option<Rc<dyn Fn()>>);
+
+impl WindowListenerHandle {
+    /// Removes the event listener, without waiting for its [Scope] to be disposed.
+    /// This can be called from inside the listener itself.
+    pub fn remove(self) {
+        if let Some(remove) = self.0 {
+            remove();
+        }
+    }
+}
+
+impl std::fmt::Debug for WindowListenerHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WindowListenerHandle").finish()
     }
 }
 
+// adds the listener, and returns a function that removes it (and frees the closure);
+// the same function is called when the scope is disposed. It can be called from inside the
+// listener itself, so the closure is only dropped once the current task has finished running it
+fn add_scoped_event_listener(
+    cx: Scope,
+    target: &web_sys::EventTarget,
+    event_name: &str,
+    modifiers: EventModifiers,
+    cb: Box<dyn FnMut(web_sys::Event)>,
+) -> Rc<dyn Fn()> {
+    let cb = Closure::wrap(cb);
+    if modifiers.needs_native_listener() {
+        let options = web_sys::AddEventListenerOptions::new();
+        options.set_passive(modifiers.passive);
+        options.set_capture(modifiers.capture);
+        _ = target.add_event_listener_with_callback_and_add_event_listener_options(
+            event_name,
+            cb.as_ref().unchecked_ref(),
+            &options,
+        );
+    } else {
+        _ = target.add_event_listener_with_callback(event_name, cb.as_ref().unchecked_ref());
+    }
+
+    let listener = RefCell::new(Some((target.clone(), event_name.to_string(), cb)));
+    let remove: Rc<dyn Fn()> = Rc::new(move || {
+        if let Some((target, event_name, cb)) = listener.take() {
+            _ = target.remove_event_listener_with_callback_and_bool(
+                &event_name,
+                cb.as_ref().unchecked_ref(),
+                modifiers.capture,
+            );
+            queue_microtask(move || drop(cb));
+        }
+    });
+    on_cleanup(cx, {
+        let remove = Rc::clone(&remove);
+        move || remove()
+    });
+    remove
+}
+
 pub fn remove_event_listeners(el: &web_sys::Element) {
     let clone = el.clone_node().unwrap_throw();
     replace_with(el, clone.unchecked_ref());
@@ -1,22 +1,26 @@
-use std::collections::HashMap;
-
 use crate::insert_before;
 
-struct NodeWrapper<'a>(&'a web_sys::Node);
+/// Maps nodes to their index in the new list. Nodes are keyed by their identity on the JS side,
+/// as several `web_sys::Node`s can refer to the same DOM node.
+struct NodeIndices(js_sys::Map);
 
-impl<'a> std::hash::Hash for NodeWrapper<'a> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        std::ptr::hash(self.0, state)
+impl NodeIndices {
+    fn new(nodes: &[web_sys::Node], start: usize, end: usize) -> Self {
+        let map = js_sys::Map::new();
+        for (i, node) in nodes.iter().enumerate().take(end).skip(start) {
+            map.set(node, &(i as u32).into());
+        }
+        Self(map)
     }
-}
 
-impl<'a> PartialEq for NodeWrapper<'a> {
-    fn eq(&self, other: &Self) -> bool {
-        std::ptr::eq(&self.0, &other.0)
+    fn get(&self, node: &web_sys::Node) -> Option<usize> {
+        self.0.get(node).as_f64().map(|i| i as usize)
     }
-}
 
-impl<'a> Eq for NodeWrapper<'a> {}
+    fn contains(&self, node: &web_sys::Node) -> bool {
+        self.0.has(node)
+    }
+}
 
 // See Sycamore implementation: https://github.com/sycamore-rs/sycamore/blob/5f58fe37599e125fdc4a85cbd51e4e1c3d359791/packages/sycamore-core/src/render.rs#L237
 // Copyright © 2021-2022 Luke Chu
@@ -42,7 +46,7 @@ pub fn reconcile_arrays(parent: &web_sys::Element, a: &mut [web_sys::Node], b: &
     let mut b_end = b_len;
     let mut a_start = 0;
     let mut b_start = 0;
-    let mut map = None::<HashMap<NodeWrapper, usize>>;
+    let mut map = None::<NodeIndices>;
 
     // Last node in a.
     let after = a[a_end - 1].next_sibling();
@@ -67,7 +71,7 @@ pub fn reconcile_arrays(parent: &web_sys::Element, a: &mut [web_sys::Node], b: &
         } else if b_end == b_start {
             // Remove.
             for node in &a[a_start..a_end] {
-                if map.is_none() || !map.as_ref().unwrap().contains_key(&NodeWrapper(node)) {
+                if map.is_none() || !map.as_ref().unwrap().contains(node) {
                     _ = parent.remove_child(node);
                 }
             }
@@ -93,16 +97,11 @@ pub fn reconcile_arrays(parent: &web_sys::Element, a: &mut [web_sys::Node], b: &
         } else {
             // Fallback to map.
             if map.is_none() {
-                let tmp = b[b_start..b_end]
-                    .iter()
-                    .enumerate()
-                    .map(|(i, g)| (NodeWrapper(&g), i))
-                    .collect();
-                map = Some(tmp);
+                map = Some(NodeIndices::new(b, b_start, b_end));
             }
             let map = map.as_ref().unwrap();
 
-            if let Some(&index) = map.get(&NodeWrapper(&a[a_start])) {
+            if let Some(index) = map.get(&a[a_start]) {
                 if b_start < index && index < b_end {
                     let mut i = a_start;
                     let mut sequence = 1;
@@ -110,7 +109,7 @@ pub fn reconcile_arrays(parent: &web_sys::Element, a: &mut [web_sys::Node], b: &
 
                     while i + 1 < a_end && i + 1 < b_end {
                         i += 1;
-                        t = map.get(&NodeWrapper(&a[i])).copied();
+                        t = map.get(&a[i]);
                         if t != Some(index + sequence) {
                             break;
                         }
@@ -1,10 +1,12 @@
+use std::{cell::RefCell, rc::Rc};
+
 use leptos_reactive::{create_render_effect, Scope};
 use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
 
 use crate::{
-    append_child, create_text_node, debug_warn, insert_before, reconcile::reconcile_arrays,
-    remove_attribute, remove_child, replace_child, replace_with, set_attribute, Attribute, Child,
-    Class, Property,
+    append_child, create_comment_node, create_text_node, debug_warn, document, insert_before,
+    reconcile::reconcile_arrays, remove_attribute, remove_child, replace_child, replace_with,
+    set_attribute, Attribute, Child, Class, ClassList, Property, Style,
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -52,6 +54,16 @@ pub fn attribute(cx: Scope, el: &web_sys::Element, attr_name: &'static str, valu
     }
 }
 
+pub fn spread_attributes(
+    cx: Scope,
+    el: &web_sys::Element,
+    attrs: impl IntoIterator<Item = (&'static str, Attribute)>,
+) {
+    for (attr_name, value) in attrs {
+        attribute(cx, el, attr_name, value);
+    }
+}
+
 fn attribute_expression(el: &web_sys::Element, attr_name: &str, value: Attribute) {
     match value {
         Attribute::String(value) => {
@@ -127,6 +139,61 @@ fn class_expression(el: &web_sys::Element, class_name: &str, value: bool) {
     }
 }
 
+pub fn class_list(cx: Scope, el: &web_sys::Element, value: ClassList) {
+    match value {
+        ClassList::Fn(f) => {
+            let el = el.clone();
+            create_render_effect(cx, move |old: Option<Vec<String>>| {
+                let new = f();
+                class_list_expression(&el, old.as_deref().unwrap_or_default(), &new);
+                new
+            });
+        }
+        ClassList::Value(names) => class_list_expression(el, &[], &names),
+    }
+}
+
+// only touches the names that changed, so that `class:` toggles on the same element are kept
+fn class_list_expression(el: &web_sys::Element, old: &[String], new: &[String]) {
+    let class_list = el.class_list();
+    for name in old {
+        if !new.contains(name) {
+            class_list.remove_1(name).unwrap_throw();
+        }
+    }
+    for name in new {
+        if !old.contains(name) {
+            class_list.add_1(name).unwrap_throw();
+        }
+    }
+}
+
+pub fn style(cx: Scope, el: &web_sys::Element, style_name: &'static str, value: Style) {
+    match value {
+        Style::Fn(f) => {
+            let el = el.clone();
+            create_render_effect(cx, move |old: Option<Option<String>>| {
+                let new = f();
+                if old.as_ref() != Some(&new) && (old.is_some() || new.is_some()) {
+                    style_expression(&el, style_name, new.as_deref())
+                }
+                new
+            });
+        }
+        Style::Value(value) => style_expression(el, style_name, value.as_deref()),
+    }
+}
+
+fn style_expression(el: &web_sys::Element, style_name: &str, value: Option<&str>) {
+    let style = el.unchecked_ref::<web_sys::HtmlElement>().style();
+    match value {
+        Some(value) => style.set_property(style_name, value).unwrap_throw(),
+        None => {
+            style.remove_property(style_name).unwrap_throw();
+        }
+    }
+}
+
 pub fn insert(
     cx: Scope,
     parent: web_sys::Node,
@@ -183,6 +250,60 @@ pub fn insert(
     }
 }
 
+/// Renders a reactive child into its own range of nodes, which ends with an empty comment, and
+/// keeps the range up to date with a nested insert.
+///
+/// The returned [Child] gives the nodes that are currently in the range without calling `f`, so
+/// the range can be moved around as a unit (like a row of a list) and its updates don't re-render
+/// whatever contains it.
+pub fn insert_range(cx: Scope, f: Rc<RefCell<dyn FnMut() -> Child>>) -> Child {
+    let end = create_comment_node();
+    // the range lives in a fragment until it's mounted
+    document()
+        .create_document_fragment()
+        .append_child(&end)
+        .unwrap_throw();
+
+    let nodes = Rc::new(RefCell::new(Vec::new()));
+    create_render_effect(cx, {
+        let end = end.clone();
+        let nodes = Rc::clone(&nodes);
+        move |current: Option<Child>| {
+            let current = current.unwrap_or(Child::Null);
+            // the range has been removed, and is about to be disposed
+            let parent = match end.parent_node() {
+                Some(parent) => parent,
+                None => return current,
+            };
+
+            let mut value = (f.borrow_mut())();
+            while let Child::Fn(f) = value {
+                value = (f.borrow_mut())();
+            }
+
+            let current = insert_expression(
+                cx,
+                parent.unchecked_into(),
+                &value,
+                current,
+                &Marker::BeforeChild(end.clone()),
+            );
+            *nodes.borrow_mut() = match &current {
+                Child::Node(node) => vec![node.clone()],
+                Child::Nodes(current_nodes) => current_nodes.clone(),
+                _ => Vec::new(),
+            };
+            current
+        }
+    });
+
+    Child::Fn(Rc::new(RefCell::new(move || {
+        let mut range = nodes.borrow().clone();
+        range.push(end.clone());
+        Child::Nodes(range)
+    })))
+}
+
 pub fn insert_expression(
     _cx: Scope,
     parent: web_sys::Element,
@@ -262,6 +383,10 @@ pub fn insert_expression(
                 if new_nodes.is_empty() {
                     clean_children(&parent, current, before, None)
                 } else if let Child::Nodes(ref mut current_nodes) = current {
+                    // a nested range (like a reactive row) may have replaced some of its own
+                    // nodes since this was last rendered
+                    current_nodes
+                        .retain(|node| node.parent_node().as_ref() == Some(parent.unchecked_ref()));
                     if current_nodes.is_empty() {
                         Child::Nodes(append_nodes(
                             parent,
@@ -272,6 +397,17 @@ pub fn insert_expression(
                         reconcile_arrays(&parent, current_nodes, new_nodes);
                         Child::Nodes(new_nodes.to_vec())
                     }
+                } else if multi {
+                    // replace the placeholder or single node that was rendered in this position
+                    let (marker, old_node) = match current {
+                        Child::Node(node) => (Some(node.clone()), Some(node)),
+                        _ => (before.as_some_node().cloned(), None),
+                    };
+                    let nodes = append_nodes(parent.clone(), new_nodes.to_vec(), marker);
+                    if let Some(old_node) = old_node {
+                        remove_child(&parent, &old_node);
+                    }
+                    Child::Nodes(nodes)
                 } else {
                     clean_children(&parent, Child::Null, &Marker::NoChildren, None);
                     append_nodes(parent, new_nodes.to_vec(), before.as_some_node().cloned());
@@ -415,7 +551,10 @@ fn clean_children(
                                     node = insert_before(parent, &node, marker.as_some_node());
                                 }
                             } else {
-                                el.unchecked_ref::<web_sys::Element>().remove();
+                                // rows may include text nodes, so remove via the parent
+                                if let Some(el_parent) = el.parent_node() {
+                                    _ = el_parent.remove_child(el);
+                                }
                             }
                         } else {
                             inserted = true;
@@ -14,10 +14,11 @@ cfg_if! {
         use leptos_reactive::*;
 
         use crate::Element;
-        use futures::{stream::FuturesUnordered, Stream, StreamExt};
+        use futures::{stream::FuturesUnordered, Future, Stream, StreamExt};
+        use std::pin::Pin;
 
         pub fn render_to_stream(view: impl Fn(Scope) -> Element + 'static) -> impl Stream<Item = String> {
-            let ((shell, pending_resources, pending_fragments, serializers), _, disposer) =
+            let ((shell, pending_resources, pending_fragments, serializers, cx), _, disposer) =
                 run_scope_undisposed({
                     move |cx| {
                         // the actual app body/template code
@@ -32,13 +33,14 @@ cfg_if! {
                             pending_resources,
                             cx.pending_fragments(),
                             cx.serialization_resolvers(),
+                            cx,
                         )
                     }
                 });
 
             let fragments = FuturesUnordered::new();
             for (fragment_id, fut) in pending_fragments {
-                fragments.push(async move { (fragment_id, fut.await) })
+                fragments.push(resolve_fragment(fragment_id, fut));
             }
 
             // HTML for the view function and script to store resources
@@ -70,18 +72,28 @@ cfg_if! {
                 )
             }))
             // stream HTML for each <Suspense/> as it resolves
-            .chain(fragments.map(|(fragment_id, html)| {
-                format!(
+            .chain(futures::stream::unfold(fragments, move |mut fragments| async move {
+                let (fragment_id, html) = fragments.next().await?;
+
+                // resolving this fragment may have registered others, like a nested <Suspense/>
+                for (fragment_id, fut) in cx.pending_fragments() {
+                    fragments.push(resolve_fragment(fragment_id, fut));
+                }
+
+                let html = format!(
                     r#"
                         <template id="{fragment_id}">{html}</template>
                         <script>
                             var frag = document.querySelector(`[data-fragment-id="{fragment_id}"]`);
                             var tpl = document.getElementById("{fragment_id}");
-                            console.log("replace", frag, "with", tpl.content.cloneNode(true));
-                            frag.replaceWith(tpl.content.cloneNode(true));
+                            if (frag) {{
+                                console.log("replace", frag, "with", tpl.content.cloneNode(true));
+                                frag.replaceWith(tpl.content.cloneNode(true));
+                            }}
                         </script>
                         "#
-                )
+                );
+                Some((html, fragments))
             }))
             // dispose of Scope
             .chain(futures::stream::once(async {
@@ -89,5 +101,12 @@ cfg_if! {
                 Default::default()
             }))
         }
+
+        fn resolve_fragment(
+            fragment_id: String,
+            fut: Pin<Box<dyn Future<Output = String>>>,
+        ) -> impl Future<Output = (String, String)> {
+            async move { (fragment_id, fut.await) }
+        }
     }
 }
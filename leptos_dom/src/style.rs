@@ -0,0 +1,85 @@
+use leptos_reactive::Scope;
+
+/// The value of a single CSS property set with `style:` in the `view` macro.
+/// `None` removes the property.
+pub enum Style {
+    Value(Option<String>),
+    Fn(Box<dyn Fn() -> Option<String>>),
+}
+
+pub trait IntoStyle {
+    fn into_style(self, cx: Scope) -> Style;
+}
+
+impl IntoStyle for String {
+    fn into_style(self, _cx: Scope) -> Style {
+        Style::Value(Some(self))
+    }
+}
+
+impl IntoStyle for Option<String> {
+    fn into_style(self, _cx: Scope) -> Style {
+        Style::Value(self)
+    }
+}
+
+impl<T, U> IntoStyle for T
+where
+    T: Fn() -> U + 'static,
+    U: IntoStyle,
+{
+    fn into_style(self, cx: Scope) -> Style {
+        let modified_fn = Box::new(move || (self)().into_style(cx).value());
+        Style::Fn(modified_fn)
+    }
+}
+
+macro_rules! style_type {
+    ($style_type:ty) => {
+        impl IntoStyle for $style_type {
+            fn into_style(self, _cx: Scope) -> Style {
+                Style::Value(Some(self.to_string()))
+            }
+        }
+
+        impl IntoStyle for Option<$style_type> {
+            fn into_style(self, _cx: Scope) -> Style {
+                Style::Value(self.map(|n| n.to_string()))
+            }
+        }
+    };
+}
+
+style_type!(&String);
+style_type!(&str);
+style_type!(usize);
+style_type!(u8);
+style_type!(u16);
+style_type!(u32);
+style_type!(u64);
+style_type!(u128);
+style_type!(isize);
+style_type!(i8);
+style_type!(i16);
+style_type!(i32);
+style_type!(i64);
+style_type!(i128);
+style_type!(f32);
+style_type!(f64);
+
+impl Style {
+    /// The current value of the property.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            Style::Value(value) => value.clone(),
+            Style::Fn(f) => f(),
+        }
+    }
+
+    /// Renders the property as a declaration like `color: red;`, or an empty string if it has no value.
+    pub fn as_value_string(&self, style_name: &'static str) -> String {
+        self.value()
+            .map(|value| format!("{style_name}: {value};"))
+            .unwrap_or_default()
+    }
+}
@@ -100,6 +100,12 @@ impl ToTokens for InlinePropsBody {
                         #[builder(default, setter(strip_option))]
                         #vis #f
                     }
+                } else if pat.path.segments.last().map(|segment| segment.ident == "SpreadAttributes").unwrap_or(false) {
+                    // captured attributes are optional
+                    quote! {
+                        #[builder(default)]
+                        #vis #f
+                    }
                 } else {
                     quote! { #vis #f }
                 }
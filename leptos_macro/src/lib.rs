@@ -34,6 +34,7 @@ use view::render_view;
 mod component;
 mod props;
 mod server;
+mod store;
 
 /// The `view` macro uses RSX (like JSX, but Rust!) It follows most of the
 /// same rules as HTML, with the following differences:
@@ -110,13 +111,16 @@ mod server;
 /// ```
 ///
 /// 5. Event handlers can be added with `on:` attributes. If the event name contains a dash, you should use `on-` as the prefix instead.
+///    Handlers for the events in `leptos_dom::ev` receive that event's specific type (like a
+///    `web_sys::MouseEvent` for `on:click`, or a `web_sys::KeyboardEvent` for `on:keydown`);
+///    handlers for any other event receive a plain `web_sys::Event`.
 /// ```rust
 /// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
 /// # run_scope(|cx| {
 /// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
 /// view! {
 ///   cx,
-///   <button on:click=|ev: web_sys::Event| {
+///   <button on:click=|ev: web_sys::MouseEvent| {
 ///     log::debug!("click event: {ev:#?}");
 ///   }>
 ///     "Click me"
@@ -127,6 +131,36 @@ mod server;
 /// # });
 /// ```
 ///
+///    Modifiers can be added after the event name, separated by colons: `:prevent` and `:stop` call
+///    `preventDefault()` and `stopPropagation()` before your handler runs, `:once` only runs it the first time,
+///    `:passive` and `:capture` set those options on the listener, and key names like `:enter`, `:escape`,
+///    `:space`, `:tab`, `:up` or `:down` only run keyboard event handlers when that key is pressed.
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// view! {
+///   cx,
+///   <form on:submit:prevent=|_| log::debug!("submitted without reloading the page")>
+///     <input on:keydown:enter=|ev: web_sys::KeyboardEvent| log::debug!("pressed {}", ev.key())/>
+///   </form>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+///    A `:passive` listener promises not to call `preventDefault()`, so it can't also use `:prevent`.
+/// ```rust,compile_fail
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// // ❌ the browser would ignore the `preventDefault()`
+/// view! { cx, <div on:wheel:passive:prevent=|_| ()></div> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
 /// 6. DOM properties can be set with `prop:` attributes, which take any primitive type or `JsValue` (or a signal
 ///    that returns a primitive or JsValue). They can also take an `Option`, in which case `Some` sets the property
 ///    and `None` deletes the property.
@@ -166,6 +200,90 @@ mod server;
 /// # });
 /// ```
 ///
+///    The `class` attribute itself can also be dynamic: it takes a string of class names, a list or set
+///    of names, or a list of `(name, bool)` pairs (or a signal that returns any of these). When it changes,
+///    only the class names that were added or removed are updated, so it can be combined with `class:`.
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (count, set_count) = create_signal(cx, 2);
+/// view! { cx, <div class={move || vec![("card", true), ("even", count() % 2 == 0)]} class:hidden={move || count() < 3}/> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// 8. Individual CSS properties can be set with `style:` attributes, which take a value (or a signal
+///    that returns one). `None` removes the property. If the property name contains a dash, you should
+///    use `style-` as the prefix instead. On the server, they're merged with the `style` attribute.
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (count, set_count) = create_signal(cx, 2);
+/// view! {
+///   cx,
+///   <div
+///     style="padding: 1em"
+///     style:color={move || if count() > 3 { "red" } else { "black" }}
+///     style-background-color={move || (count() % 2 == 0).then(|| "yellow")}
+///   >
+///     "Colorful"
+///   </div>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// 9. Elements can be stored in a [NodeRef](leptos_dom::NodeRef) with the `ref` attribute. The reference
+///    is filled once the element is mounted, and is always empty on the server.
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let input_ref = create_node_ref::<web_sys::HtmlInputElement>(cx);
+/// input_ref.on_load(cx, |input| {
+///   _ = input.focus();
+/// });
+/// view! { cx, <input type="text" ref=input_ref/> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// 10. A list of attributes ([SpreadAttributes](leptos_dom::SpreadAttributes)) can be spread onto an element
+///     with `{..attrs}`. Put spreads before any attributes whose values aren't wrapped in braces, so they
+///     aren't parsed as part of those values.
+///
+///     A component can capture attributes that aren't its props with an `attrs: SpreadAttributes` prop:
+///     any attributes with a dash in their name (like `aria-label` or `data-id`) and any `{..attrs}`
+///     spreads on the component are collected into it, so it can pass them on to one of its elements.
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let attrs: SpreadAttributes = vec![("id", "main".into_attribute(cx)), ("aria-hidden", true.into_attribute(cx))];
+/// view! { cx, <div {..attrs} class="card">"Spread"</div> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// 11. SVG and MathML elements can be the root of a view, so a component can return part of a
+///     larger drawing, like a `<g>` or a `<circle>`, and it will be created in the right namespace.
+/// ```rust
+/// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::view; use leptos_dom::wasm_bindgen::JsCast;
+/// # run_scope(|cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (radius, set_radius) = create_signal(cx, 10);
+/// view! { cx, <circle cx="50" cy="50" r=move || radius().to_string() fill="red"/> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
 /// Here’s a simple example that shows off several of these features, put together
 /// ```rust
 /// # use leptos_reactive::*; use leptos_dom::*; use leptos_macro::*; use leptos_dom as leptos; use leptos_dom::Marker; use leptos_dom::wasm_bindgen::JsCast;
@@ -177,9 +295,9 @@ mod server;
 ///
 ///     // create event handlers for our buttons
 ///     // note that `value` and `set_value` are `Copy`, so it's super easy to move them into closures
-///     let clear = move |_ev: web_sys::Event| set_value(0);
-///     let decrement = move |_ev: web_sys::Event| set_value.update(|value| *value -= 1);
-///     let increment = move |_ev: web_sys::Event| set_value.update(|value| *value += 1);
+///     let clear = move |_ev: web_sys::MouseEvent| set_value(0);
+///     let decrement = move |_ev: web_sys::MouseEvent| set_value.update(|value| *value -= 1);
+///     let increment = move |_ev: web_sys::MouseEvent| set_value.update(|value| *value += 1);
 ///
 ///     // this JSX is compiled to an HTML template string for performance
 ///     view! {
@@ -249,6 +367,47 @@ pub fn params_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     params::impl_params(&ast)
 }
 
+/// Derives a trait with a method for each field of a struct, which returns a
+/// [StoreField](leptos_reactive::StoreField) for that field when called on a
+/// [Store](leptos_reactive::Store), or on another field, containing the struct.
+///
+/// For a struct named `AppState`, the trait is named `AppStateStoreFields`, and has
+/// the same visibility as the struct.
+/// ```
+/// # use leptos::*;
+/// #[derive(Store)]
+/// struct AppState {
+///     user: User,
+///     count: i32,
+/// }
+///
+/// #[derive(Store)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// # run_scope(|cx| {
+/// let state = create_store(cx, AppState {
+///     user: User { name: "Alice".to_string() },
+///     count: 0,
+/// });
+///
+/// let name = state.user().name();
+/// state.count().set(1);
+/// name.set("Bob".to_string());
+/// assert_eq!(state.count().get(), 1);
+/// assert_eq!(state.with(|state| state.user.name.clone()), "Bob");
+/// # });
+/// ```
+#[proc_macro_derive(Store)]
+pub fn store_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    store::impl_store(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 pub(crate) fn is_component_node(node: &Node) -> bool {
     if let NodeType::Element = node.node_type {
         node.name_as_string()
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, DeriveInput};
+
+pub fn impl_store(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let trait_name = format_ident!("{}StoreFields", name);
+
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            ast.generics.span(),
+            "#[derive(Store)] does not support generic structs",
+        ));
+    }
+
+    let fields = match &ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new(
+                ast.span(),
+                "#[derive(Store)] can only be used on structs with named fields",
+            ))
+        }
+    };
+
+    let signatures = fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let ty = &field.ty;
+            let doc = format!("A [StoreField](leptos::StoreField) for `{name}::{ident}`.");
+            quote! {
+                #[doc = #doc]
+                fn #ident(&self) -> leptos::StoreField<S, #ty>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let methods = fields.iter().zip(&signatures).map(|(field, signature)| {
+        let ident = field.ident.as_ref().unwrap();
+        let field_name = ident.to_string();
+        quote! {
+            #signature {
+                self.field(#field_name, |value| &value.#ident, |value| &mut value.#ident)
+            }
+        }
+    });
+
+    let doc = format!("Accesses the fields of a [Store](leptos::Store) containing a `{name}`.");
+    Ok(quote! {
+        #[doc = #doc]
+        #vis trait #trait_name<S> {
+            #(#signatures;)*
+        }
+
+        impl<S: 'static> #trait_name<S> for leptos::StoreField<S, #name> {
+            #(#methods)*
+        }
+    })
+}
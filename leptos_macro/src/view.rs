@@ -8,6 +8,204 @@ use crate::{is_component_node, Mode};
 
 const NON_BUBBLING_EVENTS: [&str; 11] = ["load", "unload", "scroll", "focus", "blur", "loadstart", "progress", "error", "abort", "load", "loadend"];
 
+// events described in `leptos_dom::ev`, whose handlers receive a specific event type;
+// handlers for any other event receive a plain `web_sys::Event`
+const TYPED_EVENTS: [&str; 68] = [
+    "abort",
+    "animationend",
+    "animationiteration",
+    "animationstart",
+    "auxclick",
+    "beforeinput",
+    "blur",
+    "change",
+    "click",
+    "compositionend",
+    "compositionstart",
+    "compositionupdate",
+    "contextmenu",
+    "copy",
+    "cut",
+    "dblclick",
+    "drag",
+    "dragend",
+    "dragenter",
+    "dragleave",
+    "dragover",
+    "dragstart",
+    "drop",
+    "error",
+    "focus",
+    "focusin",
+    "focusout",
+    "gotpointercapture",
+    "input",
+    "invalid",
+    "keydown",
+    "keypress",
+    "keyup",
+    "load",
+    "loadend",
+    "loadstart",
+    "lostpointercapture",
+    "mousedown",
+    "mouseenter",
+    "mouseleave",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "paste",
+    "pointercancel",
+    "pointerdown",
+    "pointerenter",
+    "pointerleave",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "progress",
+    "reset",
+    "scroll",
+    "select",
+    "submit",
+    "touchcancel",
+    "touchend",
+    "touchmove",
+    "touchstart",
+    "transitioncancel",
+    "transitionend",
+    "transitionrun",
+    "transitionstart",
+    "unload",
+    "wheel",
+];
+
+fn event_listener_to_tokens(cx: &Ident, target: &TokenStream, name: &str, handler: &syn::Expr, span: Span) -> TokenStream {
+    let (event_name, modifiers) = split_event_modifiers(name);
+    if !modifiers.is_empty() {
+        let event = event_descriptor_to_tokens(event_name, span);
+        let modifiers = event_modifiers_to_tokens(&modifiers, span);
+        quote_spanned! {
+            span => ::leptos::add_event_listener_with_modifiers(#cx, #target.unchecked_ref(), #event, #modifiers, #handler)
+        }
+    } else if TYPED_EVENTS.contains(&event_name) {
+        let event = Ident::new(event_name, span);
+        quote_spanned! {
+            span => ::leptos::add_event_listener_typed(#cx, #target.unchecked_ref(), ::leptos::ev::#event, #handler)
+        }
+    } else if NON_BUBBLING_EVENTS.contains(&event_name) {
+        quote_spanned! {
+            span => ::leptos::add_event_listener_undelegated(#cx, #target.unchecked_ref(), #event_name, #handler)
+        }
+    } else {
+        quote_spanned! {
+            span => ::leptos::add_event_listener(#target.unchecked_ref(), #event_name, #handler)
+        }
+    }
+}
+
+// on the server, listeners are never added, but the handler is still type-checked
+fn ssr_event_listener_to_tokens(name: &str, handler: &syn::Expr, span: Span) -> TokenStream {
+    let (event_name, modifiers) = split_event_modifiers(name);
+    let modifiers = if modifiers.is_empty() {
+        quote! {}
+    } else {
+        let modifiers = event_modifiers_to_tokens(&modifiers, span);
+        quote_spanned! { span => let _ = #modifiers; }
+    };
+    if TYPED_EVENTS.contains(&event_name) {
+        let event = Ident::new(event_name, span);
+        quote_spanned! {
+            span => #modifiers let _  = ::leptos::ssr_event_listener_typed(::leptos::ev::#event, #handler);
+        }
+    } else {
+        quote_spanned! {
+            span => #modifiers let _  = ssr_event_listener(#handler);
+        }
+    }
+}
+
+// `click:prevent:stop` => ("click", ["prevent", "stop"])
+fn split_event_modifiers(name: &str) -> (&str, Vec<&str>) {
+    let mut parts = name.split(':');
+    let event_name = parts.next().unwrap_or_default();
+    (event_name, parts.collect())
+}
+
+fn event_descriptor_to_tokens(event_name: &str, span: Span) -> TokenStream {
+    if TYPED_EVENTS.contains(&event_name) {
+        let event = Ident::new(event_name, span);
+        quote_spanned! { span => ::leptos::ev::#event }
+    } else if NON_BUBBLING_EVENTS.contains(&event_name) {
+        quote_spanned! { span => ::leptos::ev::Custom::non_bubbling(#event_name) }
+    } else {
+        quote_spanned! { span => ::leptos::ev::Custom::new(#event_name) }
+    }
+}
+
+fn event_modifiers_to_tokens(modifiers: &[&str], span: Span) -> TokenStream {
+    let mut prevent_default = false;
+    let mut stop_propagation = false;
+    let mut once = false;
+    let mut passive = false;
+    let mut capture = false;
+    let mut key = None;
+    for modifier in modifiers {
+        match *modifier {
+            "prevent" => prevent_default = true,
+            "stop" => stop_propagation = true,
+            "once" => once = true,
+            "passive" => passive = true,
+            "capture" => capture = true,
+            other => match key_modifier(other) {
+                Some(_) if key.is_some() => {
+                    return quote_spanned! { span => compile_error!("an event listener can only filter on one key") }
+                }
+                Some(k) => key = Some(k),
+                None => {
+                    let message = format!("unknown event modifier `{other}`");
+                    return quote_spanned! { span => compile_error!(#message) };
+                }
+            },
+        }
+    }
+    if passive && prevent_default {
+        return quote_spanned! { span => compile_error!("`:passive` listeners can't call `preventDefault()`, so they can't be combined with `:prevent`") };
+    }
+    let key = match key {
+        Some(key) => quote! { Some(#key) },
+        None => quote! { None },
+    };
+    quote_spanned! {
+        span => ::leptos::ev::EventModifiers {
+            prevent_default: #prevent_default,
+            stop_propagation: #stop_propagation,
+            once: #once,
+            passive: #passive,
+            capture: #capture,
+            key: #key
+        }
+    }
+}
+
+// maps a key modifier like `on:keydown:enter` to the `KeyboardEvent.key` it filters for
+fn key_modifier(modifier: &str) -> Option<&'static str> {
+    match modifier {
+        "enter" => Some("Enter"),
+        "esc" | "escape" => Some("Escape"),
+        "space" => Some(" "),
+        "tab" => Some("Tab"),
+        "backspace" => Some("Backspace"),
+        "delete" => Some("Delete"),
+        "up" => Some("ArrowUp"),
+        "down" => Some("ArrowDown"),
+        "left" => Some("ArrowLeft"),
+        "right" => Some("ArrowRight"),
+        _ => None,
+    }
+}
+
 pub(crate) fn render_view(cx: &Ident, nodes: &[Node], mode: Mode) -> TokenStream {
     let template_uid = Ident::new(
         &format!("TEMPLATE_{}", Uuid::new_v4().simple()),
@@ -75,6 +273,7 @@ fn root_element_to_tokens(
     if is_component_node(node) {
         create_component(cx, node, mode)
     } else {
+        let namespace = Namespace::of_root(&node.name_as_string().unwrap_or_default());
         element_to_tokens(
             cx,
             node,
@@ -86,6 +285,7 @@ fn root_element_to_tokens(
             &mut navigations,
             &mut expressions,
             true,
+            namespace,
             mode,
         );
 
@@ -119,6 +319,11 @@ fn root_element_to_tokens(
                 };
 
                 let span = node.name_span().unwrap();
+                // SVG and MathML roots, like <circle>, need to be parsed inside an <svg> or <math>
+                let create_template = match namespace.template_wrapper(&node.name_as_string().unwrap_or_default()) {
+                    Some(wrapper) => quote! { leptos_dom::create_namespaced_template(#wrapper, #template) },
+                    None => quote! { leptos_dom::create_template(#template) },
+                };
 
                 let navigations = if navigations.is_empty() {
                     quote! {}
@@ -135,7 +340,7 @@ fn root_element_to_tokens(
                 quote_spanned! {
                     span => {
                         thread_local! {
-                            static #template_uid: web_sys::HtmlTemplateElement = leptos_dom::create_template(#template)
+                            static #template_uid: web_sys::HtmlTemplateElement = #create_template
                         }
 
                         #generate_root
@@ -151,6 +356,74 @@ fn root_element_to_tokens(
     }
 }
 
+// The namespace an element is parsed in. Only the root of a view needs to be told: inside an
+// <svg> or <math>, the browser's parser already puts shared tags like <a> in the right namespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Namespace {
+    Html,
+    Svg,
+    MathMl,
+}
+
+// SVG elements, except those like <a>, <script>, <style>, and <title> that are also HTML elements
+const SVG_TAGS: [&str; 61] = [
+    "animate", "animateMotion", "animateTransform", "circle", "clipPath", "defs", "desc",
+    "ellipse", "feBlend", "feColorMatrix", "feComponentTransfer", "feComposite",
+    "feConvolveMatrix", "feDiffuseLighting", "feDisplacementMap", "feDistantLight",
+    "feDropShadow", "feFlood", "feFuncA", "feFuncB", "feFuncG", "feFuncR", "feGaussianBlur",
+    "feImage", "feMerge", "feMergeNode", "feMorphology", "feOffset", "fePointLight",
+    "feSpecularLighting", "feSpotLight", "feTile", "feTurbulence", "filter", "foreignObject",
+    "g", "image", "line", "linearGradient", "marker", "mask", "metadata", "mpath", "path",
+    "pattern", "polygon", "polyline", "radialGradient", "rect", "set", "stop", "switch",
+    "symbol", "text", "textPath", "tspan", "use", "view", "hatch", "hatchpath", "solidcolor",
+];
+
+const MATHML_TAGS: [&str; 31] = [
+    "maction", "annotation", "annotation-xml", "menclose", "merror", "mfenced", "mfrac", "mi",
+    "mmultiscripts", "mn", "mo", "mover", "mpadded", "mphantom", "mprescripts", "mroot", "mrow",
+    "ms", "mspace", "msqrt", "mstyle", "msub", "msubsup", "msup", "mtable", "mtd", "mtext", "mtr",
+    "munder", "munderover", "semantics",
+];
+
+impl Namespace {
+    // The namespace of the root element of a view, which has no parent to inherit it from.
+    fn of_root(tag: &str) -> Self {
+        if SVG_TAGS.contains(&tag) {
+            Namespace::Svg
+        } else if MATHML_TAGS.contains(&tag) {
+            Namespace::MathMl
+        } else {
+            Namespace::Html.of_element(tag)
+        }
+    }
+
+    // The namespace of an element with this tag, if its parent's children are in `self`.
+    fn of_element(self, tag: &str) -> Self {
+        match tag {
+            "svg" => Namespace::Svg,
+            "math" => Namespace::MathMl,
+            _ => self,
+        }
+    }
+
+    // The namespace of the children of an element with this tag, if it is in `self`.
+    fn of_children(self, tag: &str) -> Self {
+        match (self, tag) {
+            (Namespace::Svg, "foreignObject") => Namespace::Html,
+            _ => self,
+        }
+    }
+
+    // The element a root with this tag needs to be parsed inside, if it's in `self`.
+    fn template_wrapper(self, tag: &str) -> Option<&'static str> {
+        match (self, tag) {
+            (Namespace::Svg, "svg") | (Namespace::MathMl, "math") | (Namespace::Html, _) => None,
+            (Namespace::Svg, _) => Some("svg"),
+            (Namespace::MathMl, _) => Some("math"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 enum PrevSibChange {
     Sib(Ident),
@@ -170,6 +443,7 @@ fn element_to_tokens(
     navigations: &mut Vec<TokenStream>,
     expressions: &mut Vec<TokenStream>,
     is_root_el: bool,
+    namespace: Namespace,
     mode: Mode,
 ) -> Ident {
     // create this element
@@ -208,7 +482,17 @@ fn element_to_tokens(
             .iter()
             .find(|a| a.name_as_string() == Some("class".into()))
             .map(|node| {
-                (node.name_span().expect("no span for class attribute node"), node.value_as_string().unwrap_or_default().trim().to_string())
+                let span = node.name_span().expect("no span for class attribute node");
+                match (static_string_value(node), node.value.as_ref()) {
+                    (Some(value), _) => {
+                        let value = value.trim().to_string();
+                        (span, quote_spanned! { span => #value })
+                    }
+                    (None, Some(value)) => (span, quote_spanned! {
+                        span => &leptos_dom::escape_attr(&{#value}.into_class_list(#cx).as_value_string())
+                    }),
+                    (None, None) => (span, quote_spanned! { span => "" }),
+                }
             });
 
         let class_attrs = node
@@ -255,10 +539,75 @@ fn element_to_tokens(
         }
     }
 
+    // for SSR: merge all style: attributes and style attribute
+    let mut merged_style = false;
+    if mode == Mode::Ssr {
+        let style_attrs = node
+            .attributes
+            .iter()
+            .filter_map(|node| {
+                node.name_as_string().and_then(|name| {
+                    let name = name.strip_prefix("style:").or_else(|| name.strip_prefix("style-"))?.to_string();
+                    let value = node.value.as_ref().expect("style: attributes need values");
+                    let span = node.name_span().expect("missing span for style name");
+                    Some(quote_spanned! {
+                        span => leptos_buffer.push_str(&leptos_dom::escape_attr(&{#value}.into_style(#cx).as_value_string(#name)));
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if !style_attrs.is_empty() {
+            merged_style = true;
+            expressions.push(quote::quote_spanned! {
+                span => leptos_buffer.push_str(" style=\"");
+            });
+            let style_attr = node
+                .attributes
+                .iter()
+                .find(|a| a.name_as_string() == Some("style".into()));
+            if let Some(style_attr) = style_attr {
+                let span = style_attr.name_span().expect("no span for style attribute node");
+                if let Some(value) = static_string_value(style_attr) {
+                    let value = value.trim().trim_end_matches(';');
+                    if !value.is_empty() {
+                        let value = format!("{value};");
+                        expressions.push(quote::quote_spanned! {
+                            span => leptos_buffer.push_str(&leptos_dom::escape_attr(#value));
+                        });
+                    }
+                } else if let Some(value) = style_attr.value.as_ref() {
+                    expressions.push(quote::quote_spanned! {
+                        span => if let Some(value) = {#value}.into_attribute(#cx).as_nameless_value_string() {
+                            let value = value.trim().trim_end_matches(';');
+                            if !value.is_empty() {
+                                leptos_buffer.push_str(&leptos_dom::escape_attr(value));
+                                leptos_buffer.push(';');
+                            }
+                        }
+                    });
+                }
+            }
+            for attr in style_attrs {
+                expressions.push(attr);
+            }
+            expressions.push(quote::quote_spanned! {
+                span => leptos_buffer.push('"');
+            });
+        }
+    }
+
     // attributes
     for attr in &node.attributes {
-        // SSR class attribute has just been handled
-        if !(mode == Mode::Ssr && attr.name_as_string().unwrap() == "class") {
+        // {..attrs} spreads
+        if attr.node_type == NodeType::Block {
+            expressions.push(spread_to_tokens(cx, attr, &this_el_ident, mode));
+            continue;
+        }
+
+        let attr_name = attr.name_as_string().unwrap();
+        // SSR class attribute, and style attribute if merged, have just been handled
+        if !(mode == Mode::Ssr && (attr_name == "class" || (merged_style && attr_name == "style"))) {
             attr_to_tokens(
                 cx,
                 attr,
@@ -300,7 +649,7 @@ fn element_to_tokens(
 
     // self-closing tags
     // https://developer.mozilla.org/en-US/docs/Glossary/Empty_element
-    if matches!(
+    if namespace == Namespace::Html && matches!(
         name_str.as_str(),
         "area"
             | "base"
@@ -336,6 +685,7 @@ fn element_to_tokens(
     // iterate over children
     let mut prev_sib = prev_sib;
     let multi = !node.children.is_empty();
+    let children_namespace = namespace.of_children(&name_str);
     for (idx, child) in node.children.iter().enumerate() {
         // set next sib (for any insertions)
         let next_sib = next_sibling_node(&node.children, idx + 1, next_el_id);
@@ -352,6 +702,7 @@ fn element_to_tokens(
             navigations,
             expressions,
             multi,
+            children_namespace,
             mode,
             idx == 0
         );
@@ -379,6 +730,18 @@ fn element_to_tokens(
     this_el_ident
 }
 
+// The value of an attribute if it's a string literal, like `class="a b"`. Any other
+// expression, even a plain variable name, has to be evaluated at runtime.
+fn static_string_value(node: &Node) -> Option<String> {
+    match &node.value {
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(value),
+            ..
+        })) => Some(value.value()),
+        _ => None,
+    }
+}
+
 fn next_sibling_node(children: &[Node], idx: usize, next_el_id: &mut usize) -> Option<Ident> {
     if children.len() <= idx {
         None
@@ -448,27 +811,14 @@ fn attr_to_tokens(
         };
 
         if mode == Mode::Ssr {
-            // fake the initialization; should only be used in effects or event handlers, which will never run on the server
-            // but if we don't initialize it, the compiler will complain
+            // refs are never loaded on the server
             navigations.push(quote_spanned! {
-                span => #ident = String::new();
+                span => let _ = &#ident;
             });
         } else {
-            expressions.push(match &node.value {
-                Some(expr) => {
-                    if let Some(ident) = expr_to_ident(expr) {
-                        quote_spanned! {
-                            span =>
-                                // we can't pass by reference because the _el won't live long enough (it's dropped when template returns)
-                                // so we clone here; this will be unnecessary if it's the last attribute, but very necessary otherwise
-                                #ident = #el_id.clone().unchecked_into::<web_sys::Element>();
-                        }
-                    } else {
-                        panic!("'ref' needs to be passed a variable name")
-                    }
-                }
-                _ => panic!("'ref' needs to be passed a variable name"),
-            })
+            expressions.push(quote_spanned! {
+                span => #ident.load(#el_id.unchecked_ref::<web_sys::Element>());
+            });
         }
     }
     // Event Handlers
@@ -484,21 +834,19 @@ fn attr_to_tokens(
             } else {
                 name.replacen("on-", "", 1)
             };            
-            if NON_BUBBLING_EVENTS.contains(&name.as_str()) {
-                expressions.push(quote_spanned! {
-                    span => ::leptos::add_event_listener_undelegated(#el_id.unchecked_ref(), #name, #handler);
-                });
-            } else {
-                expressions.push(quote_spanned! {
-                    span => ::leptos::add_event_listener(#el_id.unchecked_ref(), #name, #handler);
-                });
-            }
+            let listener = event_listener_to_tokens(cx, &quote! { #el_id }, &name, handler, span);
+            expressions.push(quote_spanned! {
+                span => #listener;
+            });
         } else {
+            let name = if name.starts_with("on:") { 
+                name.replacen("on:", "", 1)
+            } else {
+                name.replacen("on-", "", 1)
+            };
             // this is here to avoid warnings about unused signals
             // that are used in event listeners. I'm open to better solutions.
-            expressions.push(quote_spanned! {
-                span => let _  = ssr_event_listener(#handler);
-            });
+            expressions.push(ssr_event_listener_to_tokens(&name, handler, span));
         }
     }
     // Properties
@@ -532,6 +880,22 @@ fn attr_to_tokens(
             });
         }
     }
+    // Styles
+    else if name.starts_with("style:") || name.starts_with("style-") {
+        let name = if name.starts_with("style:") {
+            name.replacen("style:", "", 1)
+        } else {
+            name.replacen("style-", "", 1)
+        };
+        if mode == Mode::Ssr {
+            // handled separately because they need to be merged
+        } else {
+            let value = node.value.as_ref().expect("style: attributes need values");
+            expressions.push(quote_spanned! {
+                span => leptos_dom::style(#cx, #el_id.unchecked_ref(), #name, #value.into_style(#cx))
+            });
+        }
+    }
     // Attributes
     else {
         match (value, mode) {
@@ -574,6 +938,13 @@ fn attr_to_tokens(
                             leptos_buffer.push_str(&{#value}.into_attribute(#cx).as_value_string(#name));
                 });
             }
+            (AttributeValue::Dynamic(value), _) if name == "class" => {
+                // A dynamic class list is diffed against its previous value, so it doesn't
+                // clobber any class: attributes on the same element
+                expressions.push(quote_spanned! {
+                    span => leptos_dom::class_list(#cx, #el_id.unchecked_ref(), {#value}.into_class_list(#cx))
+                });
+            }
             (AttributeValue::Dynamic(value), _) => {
                 // For client-side rendering, dynamic attributes don't need to be rendered in the template
                 // They'll immediately be set synchronously before the cloned template is mounted
@@ -585,6 +956,48 @@ fn attr_to_tokens(
     }
 }
 
+fn spread_to_tokens(cx: &Ident, node: &Node, el_id: &Ident, mode: Mode) -> TokenStream {
+    let attrs = match spread_expr(node) {
+        Ok(attrs) => attrs,
+        Err(error) => return error,
+    };
+    if mode == Mode::Ssr {
+        quote_spanned! {
+            attrs.span() => for (name, value) in #attrs {
+                if let Some(value) = value.as_nameless_value_string() {
+                    leptos_buffer.push(' ');
+                    leptos_buffer.push_str(name);
+                    // an empty value is a boolean attribute, which is rendered as just its name
+                    if !value.is_empty() {
+                        leptos_buffer.push_str("=\"");
+                        leptos_buffer.push_str(&leptos_dom::escape_attr(&value));
+                        leptos_buffer.push('"');
+                    }
+                }
+            }
+        }
+    } else {
+        quote_spanned! {
+            attrs.span() => leptos_dom::spread_attributes(#cx, #el_id.unchecked_ref(), #attrs)
+        }
+    }
+}
+
+// `{..attrs}` => `attrs`
+fn spread_expr(node: &Node) -> Result<&syn::Expr, TokenStream> {
+    let value = node.value.as_ref().expect("block attributes need a value");
+    if let syn::Expr::Block(block) = value {
+        if let [syn::Stmt::Expr(syn::Expr::Range(range))] = block.block.stmts.as_slice() {
+            if let (None, syn::RangeLimits::HalfOpen(_), Some(attrs)) = (&range.from, &range.limits, &range.to) {
+                return Ok(attrs);
+            }
+        }
+    }
+    Err(quote_spanned! {
+        value.span() => compile_error!("blocks in attribute position need to spread attributes, like {..attrs}")
+    })
+}
+
 enum AttributeValue<'a> {
     Static(String),
     Dynamic(&'a syn::Expr),
@@ -604,6 +1017,7 @@ fn child_to_tokens(
     navigations: &mut Vec<TokenStream>,
     expressions: &mut Vec<TokenStream>,
     multi: bool,
+    namespace: Namespace,
     mode: Mode,
     is_first_child: bool
 ) -> PrevSibChange {
@@ -637,6 +1051,7 @@ fn child_to_tokens(
                     navigations,
                     expressions,
                     false,
+                    namespace.of_element(&node.name_as_string().unwrap_or_default()),
                     mode,
                 ))
             }
@@ -915,12 +1330,16 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
 
     let props = node.attributes.iter().filter_map(|attr| {
         let attr_name = attr.name_as_string().unwrap_or_default();
-        if attr_name.starts_with("on:")
+        if attr.node_type == NodeType::Block
+            || is_captured_attribute(&attr_name)
+            || attr_name.starts_with("on:")
             || attr_name.starts_with("on-")
             || attr_name.starts_with("prop:")
             || attr_name.starts_with("prop-")
             || attr_name.starts_with("class:")
             || attr_name.starts_with("class-")
+            || attr_name.starts_with("style:")
+            || attr_name.starts_with("style-")
             || attr_name.starts_with("attr:")
             || attr_name.starts_with("attr-")
         {
@@ -948,15 +1367,7 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
                 .value
                 .as_ref()
                 .expect("on: event listener attributes need a value");
-            if NON_BUBBLING_EVENTS.contains(&event_name) {
-                Some(quote_spanned! {
-                    span => ::leptos::add_event_listener_undelegated(#component_name.unchecked_ref(), #event_name, #handler);
-                })
-            } else {
-                Some(quote_spanned! {
-                    span => ::leptos::add_event_listener(#component_name.unchecked_ref(), #event_name, #handler)
-                })
-            }
+            Some(event_listener_to_tokens(cx, &quote! { #component_name }, event_name, handler, span))
         }
         else if let Some(event_name) = attr_name.strip_prefix("on-") {
             let span = attr.name_span().unwrap();
@@ -964,15 +1375,7 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
                 .value
                 .as_ref()
                 .expect("on- event listener attributes need a value");
-            if NON_BUBBLING_EVENTS.contains(&event_name) {
-                Some(quote_spanned! {
-                    span => ::leptos::add_event_listener_undelegated(#component_name.unchecked_ref(), #event_name, #handler);
-                })
-            } else {
-                Some(quote_spanned! {
-                    span => ::leptos::add_event_listener(#component_name.unchecked_ref(), #event_name, #handler)
-                })
-            }
+            Some(event_listener_to_tokens(cx, &quote! { #component_name }, event_name, handler, span))
         }
         // Properties
         else if let Some(name) = attr_name.strip_prefix("prop:") {
@@ -1000,6 +1403,13 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
                 span => leptos_dom::class(#cx, #component_name.unchecked_ref(), #name, #value.into_class(#cx))
             })
         }
+        // Styles
+        else if let Some(name) = attr_name.strip_prefix("style:").or_else(|| attr_name.strip_prefix("style-")) {
+            let value = attr.value.as_ref().expect("style: attributes need values");
+            Some(quote_spanned! {
+                span => leptos_dom::style(#cx, #component_name.unchecked_ref(), #name, #value.into_style(#cx))
+            })
+        }
         // Attributes
         else if let Some(name) = attr_name.strip_prefix("attr:") {
             let value = attr.value.as_ref().expect("attr: attributes need values");
@@ -1018,6 +1428,46 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
         }
     }).peekable();
 
+    // attributes that aren't props are captured by the component's `attrs` prop
+    let captured_attrs = node
+        .attributes
+        .iter()
+        .filter_map(|attr| {
+            if attr.node_type == NodeType::Block {
+                Some(match spread_expr(attr) {
+                    Ok(attrs) => quote_spanned! { attrs.span() => attrs.extend(#attrs); },
+                    Err(error) => error,
+                })
+            } else {
+                let attr_name = attr.name_as_string().unwrap_or_default();
+                if is_captured_attribute(&attr_name) {
+                    let span = attr.name_span().unwrap();
+                    let value = attr
+                        .value
+                        .as_ref()
+                        .map(|value| quote_spanned! { span => {#value} })
+                        .unwrap_or_else(|| quote_spanned! { span => true });
+                    Some(quote_spanned! {
+                        span => attrs.push((#attr_name, #value.into_attribute(#cx)));
+                    })
+                } else {
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let captured_attrs = if captured_attrs.is_empty() {
+        quote! {}
+    } else {
+        quote_spanned! {
+            span => .attrs({
+                let mut attrs = Vec::new();
+                #(#captured_attrs)*
+                attrs
+            })
+        }
+    };
+
     if other_attrs.peek().is_none() {
         quote_spanned! {
             span => create_component(#cx, move || {
@@ -1026,6 +1476,7 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
                     #cx,
                     #component_props_name::builder()
                         #(#props)*
+                        #captured_attrs
                         #children
                         .build(),
                 )
@@ -1039,6 +1490,7 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
                     #cx,
                     #component_props_name::builder()
                         #(#props)*
+                        #captured_attrs
                         #children
                         .build(),
                 );
@@ -1049,6 +1501,15 @@ fn create_component(cx: &Ident, node: &Node, mode: Mode) -> TokenStream {
     }
 }
 
+// attributes with a dash in their name, like `aria-label` or `data-id`, are never passed to components
+// as props, unless they're one of the directives like `on-` or `class-`
+fn is_captured_attribute(attr_name: &str) -> bool {
+    attr_name.contains('-')
+        && !["on-", "prop-", "class-", "style-", "attr-"]
+            .iter()
+            .any(|prefix| attr_name.starts_with(prefix))
+}
+
 fn debug_name(node: &Node) -> String {
     node.name_as_string().unwrap_or_else(|| {
         node.value_as_string()
@@ -1109,3 +1570,50 @@ fn expr_to_ident(expr: &syn::Expr) -> Option<&ExprPath> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client_view(tokens: TokenStream) -> String {
+        let nodes = syn_rsx::parse2(tokens).unwrap();
+        render_view(&Ident::new("cx", Span::call_site()), &nodes, Mode::Client).to_string()
+    }
+
+    #[test]
+    fn nested_svg_elements_stay_in_the_svg_namespace() {
+        let svg = Namespace::of_root("svg");
+        let a = svg.of_children("svg").of_element("a");
+        let text = a.of_children("a").of_element("text");
+        assert_eq!(svg, Namespace::Svg);
+        assert_eq!(a, Namespace::Svg);
+        assert_eq!(text, Namespace::Svg);
+
+        // the browser parses the whole template inside the <svg>, so it needs no wrapper
+        let view = client_view(quote! { <svg><a href="#"><text>"Home"</text></a></svg> });
+        assert!(view.contains("leptos_dom :: create_template"));
+        assert!(!view.contains("create_namespaced_template"));
+    }
+
+    #[test]
+    fn svg_roots_are_parsed_inside_an_svg() {
+        let view = client_view(quote! { <text x="10">"Home"</text> });
+        assert!(view.contains("leptos_dom :: create_namespaced_template (\"svg\""));
+
+        // an <a> on its own is an HTML link
+        assert_eq!(Namespace::of_root("a"), Namespace::Html);
+        let view = client_view(quote! { <a href="#"><text>"Home"</text></a> });
+        assert!(!view.contains("create_namespaced_template"));
+    }
+
+    #[test]
+    fn foreign_object_children_are_html() {
+        let svg = Namespace::of_root("svg");
+        let foreign_object = svg.of_children("svg").of_element("foreignObject");
+        assert_eq!(foreign_object, Namespace::Svg);
+        assert_eq!(
+            foreign_object.of_children("foreignObject").of_element("input"),
+            Namespace::Html
+        );
+    }
+}
@@ -20,7 +20,16 @@
 //!    a signal [RwSignal](crate::RwSignal) without this read-write segregation.
 //! 2. *Derived Signals:* any function that relies on another signal.
 //! 3. *Memos:* [create_memo](crate::create_memo), which returns a [Memo](crate::Memo).
-//! 4. *Resources:* [create_resource], which converts an `async` [Future] into a synchronous [Resource](crate::Resource) signal.
+//!    [create_slice](crate::create_slice) memoizes one part of an [RwSignal](crate::RwSignal), along
+//!    with a setter for that part.
+//! 4. *Type-erased signals:* [Signal](crate::Signal) wraps any readable signal, and
+//!    [MaybeSignal](crate::MaybeSignal) either a signal or a static value, for component
+//!    properties that accept any of them. The [SignalGet](crate::SignalGet), [SignalWith](crate::SignalWith),
+//!    [SignalSet](crate::SignalSet) and [SignalUpdate](crate::SignalUpdate) traits are implemented by
+//!    every signal type, for code that is generic over them.
+//! 5. *Resources:* [create_resource], which converts an `async` [Future] into a synchronous [Resource](crate::Resource) signal.
+//! 6. *Stores:* [create_store](crate::create_store), which returns a [Store](crate::Store) whose fields
+//!    can be read and updated as signals of their own.
 //!
 //! ### Effects
 //! 1. Use [create_effect](crate::create_effect) when you need to synchronize the reactive system
@@ -74,7 +83,11 @@ mod scope;
 mod selector;
 mod serialization;
 mod signal;
+mod signal_traits;
+mod signal_wrappers;
+mod slice;
 mod spawn;
+mod store;
 mod suspense;
 
 pub use context::*;
@@ -87,7 +100,11 @@ pub use scope::*;
 pub use selector::*;
 pub use serialization::*;
 pub use signal::*;
+pub use signal_traits::*;
+pub use signal_wrappers::*;
+pub use slice::*;
 pub use spawn::*;
+pub use store::*;
 pub use suspense::*;
 
 #[doc(hidden)]
@@ -88,18 +88,29 @@ where
         self.with(T::clone)
     }
 
-    pub fn with<U>(&self, f: impl Fn(&T) -> U) -> U {
+    pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
         // okay to unwrap here, because the value will *always* have initially
         // been set by the effect, synchronously
         self.0
             .with(|n| f(n.as_ref().expect("Memo is missing its initial value")))
     }
 
-    pub(crate) fn try_with<U>(&self, f: impl Fn(&T) -> U) -> Result<U, SignalError> {
+    /// Applies a function to the current value of the memo, and subscribes the running effect
+    /// to it. Returns an error, rather than panicking, if its value can't be read.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
         self.0
             .try_with(|n| f(n.as_ref().expect("Memo is missing its initial value")))
     }
 
+    /// Clones and returns the current value of the memo, and subscribes the running effect
+    /// to it. Returns an error, rather than panicking, if its value can't be read.
+    pub fn try_get(&self) -> Result<T, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+
     #[cfg(feature = "hydrate")]
     pub(crate) fn subscribe(&self) {
         self.0.subscribe()
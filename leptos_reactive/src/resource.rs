@@ -12,7 +12,7 @@ use std::{
 use crate::{
     create_effect, create_isomorphic_effect, create_memo, create_signal, queue_microtask,
     runtime::Runtime, serialization::Serializable, spawn::spawn_local, use_context, Memo,
-    ReadSignal, Scope, ScopeProperty, SuspenseContext, WriteSignal,
+    ReadSignal, Scope, ScopeProperty, SignalError, SuspenseContext, WriteSignal,
 };
 
 /// Creates [Resource](crate::Resource), which is a signal that reflects the
@@ -347,6 +347,23 @@ where
             .resource(self.id, |resource: &ResourceState<S, T>| resource.with(f))
     }
 
+    /// Applies a function to the current value of the resource, and subscribes the running
+    /// effect to this resource, like [Resource::with]. Returns an error, rather than panicking,
+    /// if the resource has been disposed.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<Option<U>, SignalError> {
+        self.runtime
+            .try_resource(self.id, |resource: &ResourceState<S, T>| resource.try_with(f))?
+    }
+
+    /// Clones and returns the current value of the resource, like [Resource::read]. Returns an
+    /// error, rather than panicking, if the resource has been disposed.
+    pub fn try_read(&self) -> Result<Option<T>, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+
     pub fn loading(&self) -> bool {
         self.runtime
             .resource(self.id, |resource: &ResourceState<S, T>| {
@@ -476,13 +493,18 @@ where
     }
 
     pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> Option<U> {
-        let suspense_cx = use_context::<SuspenseContext>(self.scope);
+        self.try_with(f).ok().flatten()
+    }
+
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<Option<U>, SignalError> {
+        // reads while a boundary is rendering belong to that boundary; otherwise, fall back to
+        // the nearest boundary provided through context, unless one is already tracking this resource
+        let current_boundary = SuspenseContext::current(self.scope);
+        let in_boundary = current_boundary.is_some();
+        let suspense_cx =
+            current_boundary.or_else(|| use_context::<SuspenseContext>(self.scope));
 
-        let v = self
-            .value
-            .try_with(|n| n.as_ref().map(|n| Some(f(n))))
-            .ok()?
-            .flatten();
+        let v = self.value.try_with(|n| n.as_ref().map(f))?;
 
         let suspense_contexts = self.suspense_contexts.clone();
         let has_value = v.is_some();
@@ -490,7 +512,7 @@ where
         let increment = move |_: Option<()>| {
             if let Some(s) = &suspense_cx {
                 let mut contexts = suspense_contexts.borrow_mut();
-                if !contexts.contains(s) {
+                if !contexts.contains(s) && (in_boundary || contexts.is_empty()) {
                     contexts.insert(*s);
 
                     // on subsequent reads, increment will be triggered in load()
@@ -504,7 +526,7 @@ where
         };
 
         create_isomorphic_effect(self.scope, increment);
-        v
+        Ok(v)
     }
 
     pub fn refetch(&self) {
@@ -1,7 +1,7 @@
 use crate::{
     hydration::SharedContext, serialization::Serializable, AnyEffect, AnyResource, Effect,
     EffectId, Memo, ReadSignal, ResourceId, ResourceState, RwSignal, Scope, ScopeDisposer, ScopeId,
-    ScopeProperty, SignalId, WriteSignal,
+    ScopeProperty, SignalError, SignalId, SuspenseContext, WriteSignal,
 };
 use futures::stream::FuturesUnordered;
 use slotmap::{SecondaryMap, SlotMap, SparseSecondaryMap};
@@ -34,6 +34,7 @@ pub(crate) struct Runtime {
     pub effects: RefCell<SlotMap<EffectId, Rc<RefCell<dyn AnyEffect>>>>,
     pub effect_sources: RefCell<SecondaryMap<EffectId, RefCell<HashSet<SignalId>>>>,
     pub resources: RefCell<SlotMap<ResourceId, AnyResource>>,
+    pub suspense_boundaries: RefCell<Vec<SuspenseContext>>,
 }
 
 impl Debug for Runtime {
@@ -115,6 +116,23 @@ impl Runtime {
         }
     }
 
+    pub(crate) fn dispose_signal(&self, id: SignalId) {
+        // remove the signal
+        self.signals.borrow_mut().remove(id);
+        let subs = self.signal_subscribers.borrow_mut().remove(id);
+
+        // each of the subs needs to remove the signal from its dependencies
+        // so that it doesn't try to read the (now disposed) signal
+        if let Some(subs) = subs {
+            let source_map = self.effect_sources.borrow();
+            for effect in subs.borrow().iter() {
+                if let Some(effect_sources) = source_map.get(*effect) {
+                    effect_sources.borrow_mut().remove(&id);
+                }
+            }
+        }
+    }
+
     pub(crate) fn create_effect<T>(
         &'static self,
         f: impl FnMut(Option<T>) -> T + 'static,
@@ -218,27 +236,31 @@ impl Runtime {
         S: Debug + 'static,
         T: Debug + 'static,
     {
-        let resources = self.resources.borrow();
-        let res = resources.get(id);
-        if let Some(res) = res {
-            let res_state = match res {
-                AnyResource::Unserializable(res) => res.as_any(),
-                AnyResource::Serializable(res) => res.as_any(),
-            }
-            .downcast_ref::<ResourceState<S, T>>();
+        match self.try_resource(id, f) {
+            Ok(value) => value,
+            Err(SignalError::Disposed) => panic!("couldn't locate {id:?}"),
+            Err(SignalError::Type(ty)) => panic!("couldn't convert {id:?} to {ty}"),
+        }
+    }
 
-            if let Some(n) = res_state {
-                f(n)
-            } else {
-                panic!(
-                    "couldn't convert {id:?} to ResourceState<{}, {}>",
-                    std::any::type_name::<S>(),
-                    std::any::type_name::<T>(),
-                );
-            }
-        } else {
-            panic!("couldn't locate {id:?}");
+    pub(crate) fn try_resource<S, T, U>(
+        &self,
+        id: ResourceId,
+        f: impl FnOnce(&ResourceState<S, T>) -> U,
+    ) -> Result<U, SignalError>
+    where
+        S: Debug + 'static,
+        T: Debug + 'static,
+    {
+        let resources = self.resources.borrow();
+        let res = resources.get(id).ok_or(SignalError::Disposed)?;
+        let res_state = match res {
+            AnyResource::Unserializable(res) => res.as_any(),
+            AnyResource::Serializable(res) => res.as_any(),
         }
+        .downcast_ref::<ResourceState<S, T>>()
+        .ok_or_else(|| SignalError::Type(std::any::type_name::<ResourceState<S, T>>()))?;
+        Ok(f(res_state))
     }
 
     /// Returns IDs for all [Resource]s found on any scope.
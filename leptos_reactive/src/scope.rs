@@ -118,22 +118,7 @@ impl Scope {
         if let Some(owned) = owned {
             for property in owned {
                 match property {
-                    ScopeProperty::Signal(id) => {
-                        // remove the signal
-                        self.runtime.signals.borrow_mut().remove(id);
-                        let subs = self.runtime.signal_subscribers.borrow_mut().remove(id);
-
-                        // each of the subs needs to remove the signal from its dependencies
-                        // so that it doesn't try to read the (now disposed) signal
-                        if let Some(subs) = subs {
-                            let source_map = self.runtime.effect_sources.borrow();
-                            for effect in subs.borrow().iter() {
-                                if let Some(effect_sources) = source_map.get(*effect) {
-                                    effect_sources.borrow_mut().remove(&id);
-                                }
-                            }
-                        }
-                    }
+                    ScopeProperty::Signal(id) => self.runtime.dispose_signal(id),
                     ScopeProperty::Effect(id) => {
                         self.runtime.effects.borrow_mut().remove(id);
                         self.runtime.effect_sources.borrow_mut().remove(id);
@@ -146,6 +131,10 @@ impl Scope {
         }
     }
 
+    pub(crate) fn is_disposed(&self) -> bool {
+        !self.runtime.scopes.borrow().contains_key(self.id)
+    }
+
     pub(crate) fn with_scope_property(&self, f: impl FnOnce(&mut Vec<ScopeProperty>)) {
         let scopes = self.runtime.scopes.borrow();
         let scope = scopes
@@ -349,26 +338,58 @@ impl Scope {
         key: &str,
         resolver: impl FnOnce() -> String + 'static,
     ) {
-        use crate::create_isomorphic_effect;
-        use futures::StreamExt;
+        self.register_fragment(
+            key,
+            move || context.pending_resources.try_with(|n| *n == 0).unwrap_or(true),
+            resolver,
+        )
+    }
+
+    /// Registers a fragment of HTML to be streamed during server rendering. `resolver` is called
+    /// to render the fragment once `ready` returns `true`; `ready` is tracked, so it can read
+    /// any signals that determine when the fragment can be shown.
+    ///
+    /// Fragments registered while another fragment is being resolved (for example, by a
+    /// `<Suspense/>` nested inside another one) are streamed after it.
+    pub fn register_fragment(
+        &self,
+        key: &str,
+        ready: impl Fn() -> bool + 'static,
+        resolver: impl FnOnce() -> String + 'static,
+    ) {
+        if self.runtime.shared_context.borrow().is_some() {
+            let ready = self.until(ready);
+            self.register_fragment_future(key, async move {
+                ready.await;
+                resolver()
+            });
+        }
+    }
 
+    /// Registers a future that renders a fragment of HTML, which is streamed during server
+    /// rendering once the future resolves.
+    pub fn register_fragment_future(&self, key: &str, fragment: impl Future<Output = String> + 'static) {
         if let Some(ref mut shared_context) = *self.runtime.shared_context.borrow_mut() {
-            let (mut tx, mut rx) = futures::channel::mpsc::channel::<()>(1);
+            shared_context
+                .pending_fragments
+                .insert(key.to_string(), Box::pin(fragment));
+        }
+    }
 
-            create_isomorphic_effect(*self, move |_| {
-                let pending = context.pending_resources.try_with(|n| *n).unwrap_or(0);
-                if pending == 0 {
-                    _ = tx.try_send(());
-                }
-            });
+    /// Returns a future that resolves once `ready` returns `true`. `ready` is tracked, so it can
+    /// read any signals that determine when it's ready.
+    pub fn until(&self, ready: impl Fn() -> bool + 'static) -> impl Future<Output = ()> {
+        use crate::create_isomorphic_effect;
+        use futures::StreamExt;
 
-            shared_context.pending_fragments.insert(
-                key.to_string(),
-                Box::pin(async move {
-                    rx.next().await;
-                    resolver()
-                }),
-            );
+        let (mut tx, mut rx) = futures::channel::mpsc::channel::<()>(1);
+        create_isomorphic_effect(*self, move |_| {
+            if ready() {
+                _ = tx.try_send(());
+            }
+        });
+        async move {
+            rx.next().await;
         }
     }
 
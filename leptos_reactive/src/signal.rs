@@ -176,12 +176,33 @@ where
         self.id.with(self.runtime, T::clone)
     }
 
-    /// Applies the function to the current Signal, if it exists, and subscribes
-    /// the running effect.
-    pub(crate) fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
+    /// Applies a function to the current value of the signal, and subscribes the running
+    /// effect to this signal. Unlike [ReadSignal::with], this returns an error rather than
+    /// panicking if the signal has been disposed.
+    /// ```
+    /// # use leptos_reactive::*;
+    /// let ((count, set_count), _, disposer) = run_scope_undisposed(|cx| create_signal(cx, 0));
+    /// assert_eq!(count.try_with(|n| *n + 1), Ok(1));
+    ///
+    /// // e.g., the component that created the signal has been removed
+    /// disposer.dispose();
+    /// assert_eq!(count.try_with(|n| *n + 1), Err(SignalError::Disposed));
+    /// assert!(!set_count.try_update(|n| *n += 1));
+    /// ```
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
         self.id.try_with(self.runtime, f)
     }
 
+    /// Clones and returns the current value of the signal, and subscribes the running effect
+    /// to this signal. Unlike [ReadSignal::get], this returns an error rather than panicking
+    /// if the signal has been disposed.
+    pub fn try_get(&self) -> Result<T, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+
     /// Generates a [Stream] that emits the new value of the signal whenever it changes.
     pub fn to_stream(&self) -> impl Stream<Item = T>
     where
@@ -335,6 +356,19 @@ where
     pub fn set(&self, new_value: T) {
         self.id.update(self.runtime, |n| *n = new_value)
     }
+
+    /// Applies a function to the current value to mutate it in place and notifies subscribers,
+    /// like [WriteSignal::update]. Returns `false`, rather than panicking, if the signal has
+    /// been disposed, in which case the function isn't called.
+    pub fn try_update(&self, f: impl FnOnce(&mut T)) -> bool {
+        self.id.try_update(self.runtime, f).is_ok()
+    }
+
+    /// Sets the signal’s value and notifies subscribers, like [WriteSignal::set]. Returns
+    /// `false`, rather than panicking, if the signal has been disposed.
+    pub fn try_set(&self, new_value: T) -> bool {
+        self.id.try_update(self.runtime, |n| *n = new_value).is_ok()
+    }
 }
 
 impl<T> Clone for WriteSignal<T> {
@@ -538,6 +572,34 @@ where
         self.id.update(self.runtime, |n| *n = value)
     }
 
+    /// Applies a function to the current value of the signal, and subscribes the running
+    /// effect to this signal. Returns an error, rather than panicking, if the signal has
+    /// been disposed.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
+        self.id.try_with(self.runtime, f)
+    }
+
+    /// Clones and returns the current value of the signal, and subscribes the running effect
+    /// to this signal. Returns an error, rather than panicking, if the signal has been disposed.
+    pub fn try_get(&self) -> Result<T, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+
+    /// Applies a function to the current value to mutate it in place and notifies subscribers.
+    /// Returns `false`, rather than panicking, if the signal has been disposed.
+    pub fn try_update(&self, f: impl FnOnce(&mut T)) -> bool {
+        self.id.try_update(self.runtime, f).is_ok()
+    }
+
+    /// Sets the signal’s value and notifies subscribers. Returns `false`, rather than
+    /// panicking, if the signal has been disposed.
+    pub fn try_set(&self, value: T) -> bool {
+        self.id.try_update(self.runtime, |n| *n = value).is_ok()
+    }
+
     /// Returns a read-only handle to the signal.
     ///
     /// Useful if you're trying to give read access to another component but ensure that it can't write
@@ -606,10 +668,16 @@ where
 // Internals
 slotmap::new_key_type! { pub struct SignalId; }
 
-#[derive(Debug, Error)]
-pub(crate) enum SignalError {
+/// The reason a signal couldn't be accessed by one of its fallible methods, like
+/// [ReadSignal::try_with].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SignalError {
+    /// The signal's [Scope] has been disposed, so the signal no longer exists. This usually
+    /// happens when a callback, like the end of an `async` block, outlives the component
+    /// that created it.
     #[error("tried to access a signal that had been disposed")]
     Disposed,
+    /// The signal's value couldn't be cast to the expected type.
     #[error("error casting signal to type {0}")]
     Type(&'static str),
 }
@@ -633,16 +701,10 @@ impl SignalId {
     where
         T: 'static,
     {
-        // get the value
+        // get the value; fails without warning, since the caller is checking the result
         let value = {
             let signals = runtime.signals.borrow();
-            match signals.get(*self).cloned().ok_or(SignalError::Disposed) {
-                Ok(s) => Ok(s),
-                Err(e) => {
-                    debug_warn!("[Signal::try_with] {e}");
-                    Err(e)
-                }
-            }
+            signals.get(*self).cloned().ok_or(SignalError::Disposed)
         }?;
         let value = value.borrow();
         let value = value
@@ -668,65 +730,83 @@ impl SignalId {
     where
         T: 'static,
     {
-        self.try_with_no_subscription(runtime, f).unwrap()
+        self.try_with_no_subscription(runtime, f)
+            .map_err(|e| {
+                debug_warn!("[Signal::with_no_subscription] {e}");
+                e
+            })
+            .unwrap()
     }
 
     pub(crate) fn with<T, U>(&self, runtime: &Runtime, f: impl FnOnce(&T) -> U) -> U
     where
         T: 'static,
     {
-        self.try_with(runtime, f).unwrap()
+        self.try_with(runtime, f)
+            .map_err(|e| {
+                debug_warn!("[Signal::with] {e}");
+                e
+            })
+            .unwrap()
     }
 
     pub(crate) fn update<T>(&self, runtime: &Runtime, f: impl FnOnce(&mut T))
+    where
+        T: 'static,
+    {
+        match self.try_update(runtime, f) {
+            Ok(()) => {}
+            Err(SignalError::Disposed) => debug_warn!(
+                "[Signal::update] You’re trying to update a Signal<{}> that has already been disposed of. This is probably either a logic error in a component that creates and disposes of scopes, or a Resource resolving after its scope has been dropped without having been cleaned up.",
+                std::any::type_name::<T>()
+            ),
+            Err(SignalError::Type(type_name)) => debug_warn!(
+                "[Signal::update] failed when downcasting to Signal<{}>",
+                type_name
+            ),
+        }
+    }
+
+    // Fails without warning if the signal couldn't be updated, e.g., because it has been
+    // disposed, so the public `try_update` methods can be used to check for that.
+    pub(crate) fn try_update<T>(
+        &self,
+        runtime: &Runtime,
+        f: impl FnOnce(&mut T),
+    ) -> Result<(), SignalError>
     where
         T: 'static,
     {
         // update the value
-        let updated = {
+        {
             let value = {
                 let signals = runtime.signals.borrow();
-                signals.get(*self).cloned()
+                signals.get(*self).cloned().ok_or(SignalError::Disposed)?
             };
-            if let Some(value) = value {
-                let mut value = value.borrow_mut();
-                if let Some(value) = value.downcast_mut::<T>() {
-                    f(value);
-                    true
-                } else {
-                    debug_warn!(
-                        "[Signal::update] failed when downcasting to Signal<{}>",
-                        std::any::type_name::<T>()
-                    );
-                    false
-                }
-            } else {
-                debug_warn!(
-                    "[Signal::update] You’re trying to update a Signal<{}> that has already been disposed of. This is probably either a logic error in a component that creates and disposes of scopes, or a Resource resolving after its scope has been dropped without having been cleaned up.",
-                    std::any::type_name::<T>()
-                );
-                false
-            }
-        };
+            let mut value = value.borrow_mut();
+            let value = value
+                .downcast_mut::<T>()
+                .ok_or_else(|| SignalError::Type(std::any::type_name::<T>()))?;
+            f(value);
+        }
 
         // notify subscribers
-        if updated {
-            let subs = {
-                let subs = runtime.signal_subscribers.borrow();
-                let subs = subs.get(*self);
-                subs.map(|subs| subs.borrow().clone())
-            };
-            if let Some(subs) = subs {
-                for sub in subs {
-                    let effect = {
-                        let effects = runtime.effects.borrow();
-                        effects.get(sub).cloned()
-                    };
-                    if let Some(effect) = effect {
-                        effect.borrow_mut().run(sub, runtime);
-                    }
+        let subs = {
+            let subs = runtime.signal_subscribers.borrow();
+            let subs = subs.get(*self);
+            subs.map(|subs| subs.borrow().clone())
+        };
+        if let Some(subs) = subs {
+            for sub in subs {
+                let effect = {
+                    let effects = runtime.effects.borrow();
+                    effects.get(sub).cloned()
+                };
+                if let Some(effect) = effect {
+                    effect.borrow_mut().run(sub, runtime);
                 }
             }
         }
+        Ok(())
     }
 }
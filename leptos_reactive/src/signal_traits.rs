@@ -0,0 +1,88 @@
+use crate::{Memo, MaybeSignal, ReadSignal, RwSignal, Signal, StoreField, WriteSignal};
+
+/// Clones and returns the current value of a signal, and subscribes the running effect to it.
+pub trait SignalGet<T> {
+    /// Clones and returns the current value, and subscribes the running effect to the signal.
+    fn get(&self) -> T;
+}
+
+/// Applies a function to the current value of a signal, and subscribes the running effect to it.
+pub trait SignalWith<T> {
+    /// Applies a function to the current value, and subscribes the running effect to the signal.
+    fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U;
+}
+
+/// Sets the value of a signal and notifies its subscribers.
+pub trait SignalSet<T> {
+    /// Sets the value and notifies subscribers.
+    fn set(&self, new_value: T);
+}
+
+/// Mutates the value of a signal in place and notifies its subscribers.
+pub trait SignalUpdate<T> {
+    /// Applies a function to the current value to mutate it in place, and notifies subscribers.
+    fn update(&self, f: impl FnOnce(&mut T));
+}
+
+macro_rules! impl_get_with {
+    ($($ty:ident),*) => {
+        $(
+            impl<T: Clone> SignalGet<T> for $ty<T> {
+                fn get(&self) -> T {
+                    $ty::get(self)
+                }
+            }
+
+            impl<T> SignalWith<T> for $ty<T> {
+                fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+                    $ty::with(self, f)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_set_update {
+    ($($ty:ident),*) => {
+        $(
+            impl<T> SignalSet<T> for $ty<T> {
+                fn set(&self, new_value: T) {
+                    $ty::set(self, new_value)
+                }
+            }
+
+            impl<T> SignalUpdate<T> for $ty<T> {
+                fn update(&self, f: impl FnOnce(&mut T)) {
+                    $ty::update(self, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_get_with!(ReadSignal, RwSignal, Memo, Signal, MaybeSignal);
+impl_set_update!(WriteSignal, RwSignal);
+
+impl<S, T: Clone> SignalGet<T> for StoreField<S, T> {
+    fn get(&self) -> T {
+        StoreField::get(self)
+    }
+}
+
+impl<S, T> SignalWith<T> for StoreField<S, T> {
+    fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        StoreField::with(self, f)
+    }
+}
+
+impl<S, T> SignalSet<T> for StoreField<S, T> {
+    fn set(&self, new_value: T) {
+        StoreField::set(self, new_value)
+    }
+}
+
+impl<S, T> SignalUpdate<T> for StoreField<S, T> {
+    fn update(&self, f: impl FnOnce(&mut T)) {
+        StoreField::update(self, f)
+    }
+}
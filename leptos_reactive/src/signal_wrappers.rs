@@ -0,0 +1,327 @@
+use std::{fmt::Debug, rc::Rc};
+
+use crate::{Memo, ReadSignal, RwSignal, SignalError};
+
+/// A readable signal of any kind: a [ReadSignal], an [RwSignal], a [Memo], or a derived signal
+/// (a closure that reads other signals).
+///
+/// This is useful for a component property that should accept any reactive value, without the
+/// component being generic over `Fn() -> T`.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(|cx| {
+/// let (count, set_count) = create_signal(cx, 2);
+/// let double_count = Signal::derive(move || count.get() * 2);
+/// let memoized = create_memo(cx, move |_| count.get() * 3);
+///
+/// // this function takes any kind of signal
+/// fn above_three(n: &Signal<i32>) -> bool {
+///     n.get() > 3
+/// }
+///
+/// assert!(!above_three(&count.into()));
+/// assert!(above_three(&double_count));
+/// assert!(above_three(&memoized.into()));
+///
+/// set_count.set(1);
+/// assert!(!above_three(&double_count));
+/// # }).dispose();
+/// ```
+pub enum Signal<T>
+where
+    T: 'static,
+{
+    /// A readable signal.
+    ReadSignal(ReadSignal<T>),
+    /// A memo.
+    Memo(Memo<T>),
+    /// A derived signal.
+    Derived(Rc<dyn Fn() -> T>),
+}
+
+impl<T> Signal<T>
+where
+    T: 'static,
+{
+    /// Wraps a derived signal, i.e., any closure that reads other signals.
+    pub fn derive(derived_signal: impl Fn() -> T + 'static) -> Self {
+        Self::Derived(Rc::new(derived_signal))
+    }
+
+    /// Applies a function to the current value of the signal, and subscribes the running effect
+    /// to the signal.
+    pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        match self {
+            Self::ReadSignal(signal) => signal.with(f),
+            Self::Memo(memo) => memo.with(f),
+            Self::Derived(derived) => f(&derived()),
+        }
+    }
+
+    /// Clones and returns the current value of the signal, and subscribes the running effect
+    /// to the signal.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            Self::ReadSignal(signal) => signal.get(),
+            Self::Memo(memo) => memo.get(),
+            Self::Derived(derived) => derived(),
+        }
+    }
+
+    /// Applies a function to the current value of the signal, and subscribes the running effect
+    /// to the signal. Returns an error, rather than panicking, if the signal has been disposed.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
+        match self {
+            Self::ReadSignal(signal) => signal.try_with(f),
+            Self::Memo(memo) => memo.try_with(f),
+            Self::Derived(derived) => Ok(f(&derived())),
+        }
+    }
+
+    /// Clones and returns the current value of the signal, and subscribes the running effect
+    /// to the signal. Returns an error, rather than panicking, if the signal has been disposed.
+    pub fn try_get(&self) -> Result<T, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::ReadSignal(signal) => Self::ReadSignal(*signal),
+            Self::Memo(memo) => Self::Memo(*memo),
+            Self::Derived(derived) => Self::Derived(Rc::clone(derived)),
+        }
+    }
+}
+
+impl<T> Debug for Signal<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReadSignal(signal) => f.debug_tuple("ReadSignal").field(signal).finish(),
+            Self::Memo(memo) => f.debug_tuple("Memo").field(memo).finish(),
+            Self::Derived(_) => f.debug_tuple("Derived").finish(),
+        }
+    }
+}
+
+impl<T> From<ReadSignal<T>> for Signal<T> {
+    fn from(value: ReadSignal<T>) -> Self {
+        Self::ReadSignal(value)
+    }
+}
+
+impl<T> From<RwSignal<T>> for Signal<T> {
+    fn from(value: RwSignal<T>) -> Self {
+        Self::ReadSignal(value.read_only())
+    }
+}
+
+impl<T> From<Memo<T>> for Signal<T> {
+    fn from(value: Memo<T>) -> Self {
+        Self::Memo(value)
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<T> FnOnce<()> for Signal<T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<T> FnMut<()> for Signal<T>
+where
+    T: Clone,
+{
+    extern "rust-call" fn call_mut(&mut self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<T> Fn<()> for Signal<T>
+where
+    T: Clone,
+{
+    extern "rust-call" fn call(&self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+/// A value that is either static or a [Signal], for a component property that may or may not
+/// need to change over time.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(|cx| {
+/// let (count, set_count) = create_signal(cx, 2);
+///
+/// fn above_three(n: impl Into<MaybeSignal<i32>>) -> bool {
+///     n.into().get() > 3
+/// }
+///
+/// assert!(above_three(5));
+/// assert!(!above_three(count));
+/// assert!(above_three(Signal::derive(move || count.get() * 2)));
+/// # }).dispose();
+/// ```
+pub enum MaybeSignal<T>
+where
+    T: 'static,
+{
+    /// A value that never changes.
+    Static(T),
+    /// A value that can change.
+    Dynamic(Signal<T>),
+}
+
+impl<T> MaybeSignal<T>
+where
+    T: 'static,
+{
+    /// Applies a function to the current value, and subscribes the running effect to it if it
+    /// can change.
+    pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        match self {
+            Self::Static(value) => f(value),
+            Self::Dynamic(signal) => signal.with(f),
+        }
+    }
+
+    /// Clones and returns the current value, and subscribes the running effect to it if it
+    /// can change.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            Self::Static(value) => value.clone(),
+            Self::Dynamic(signal) => signal.get(),
+        }
+    }
+
+    /// Applies a function to the current value, and subscribes the running effect to it if it
+    /// can change. Returns an error, rather than panicking, if the signal has been disposed.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
+        match self {
+            Self::Static(value) => Ok(f(value)),
+            Self::Dynamic(signal) => signal.try_with(f),
+        }
+    }
+
+    /// Clones and returns the current value, and subscribes the running effect to it if it can
+    /// change. Returns an error, rather than panicking, if the signal has been disposed.
+    pub fn try_get(&self) -> Result<T, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+}
+
+impl<T> Clone for MaybeSignal<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Static(value) => Self::Static(value.clone()),
+            Self::Dynamic(signal) => Self::Dynamic(signal.clone()),
+        }
+    }
+}
+
+impl<T> Debug for MaybeSignal<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Static(value) => f.debug_tuple("Static").field(value).finish(),
+            Self::Dynamic(signal) => f.debug_tuple("Dynamic").field(signal).finish(),
+        }
+    }
+}
+
+impl<T> From<T> for MaybeSignal<T> {
+    fn from(value: T) -> Self {
+        Self::Static(value)
+    }
+}
+
+impl From<&str> for MaybeSignal<String> {
+    fn from(value: &str) -> Self {
+        Self::Static(value.to_string())
+    }
+}
+
+impl<T> From<Signal<T>> for MaybeSignal<T> {
+    fn from(value: Signal<T>) -> Self {
+        Self::Dynamic(value)
+    }
+}
+
+impl<T> From<ReadSignal<T>> for MaybeSignal<T> {
+    fn from(value: ReadSignal<T>) -> Self {
+        Self::Dynamic(value.into())
+    }
+}
+
+impl<T> From<RwSignal<T>> for MaybeSignal<T> {
+    fn from(value: RwSignal<T>) -> Self {
+        Self::Dynamic(value.into())
+    }
+}
+
+impl<T> From<Memo<T>> for MaybeSignal<T> {
+    fn from(value: Memo<T>) -> Self {
+        Self::Dynamic(value.into())
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<T> FnOnce<()> for MaybeSignal<T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<T> FnMut<()> for MaybeSignal<T>
+where
+    T: Clone,
+{
+    extern "rust-call" fn call_mut(&mut self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<T> Fn<()> for MaybeSignal<T>
+where
+    T: Clone,
+{
+    extern "rust-call" fn call(&self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
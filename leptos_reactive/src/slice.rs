@@ -0,0 +1,56 @@
+use std::fmt::Debug;
+
+use crate::{create_memo, Memo, RwSignal, Scope};
+
+/// Derives a getter and setter for one part of the value in an [RwSignal], which can be
+/// passed to a child component that should only see that part of the state.
+///
+/// The getter is a [Memo], so it only notifies its subscribers when the part it returns changes,
+/// rather than whenever the signal changes. The setter updates the signal in place with
+/// [RwSignal::update].
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::Cell, rc::Rc};
+/// # create_scope(|cx| {
+/// #[derive(Default)]
+/// struct State {
+///     count: i32,
+///     name: String,
+/// }
+///
+/// let state = create_rw_signal(cx, State::default());
+/// let (count, set_count) = create_slice(cx, state, |state| state.count, |state, n| state.count = n);
+///
+/// let runs = Rc::new(Cell::new(0));
+/// create_isomorphic_effect(cx, {
+///     let runs = Rc::clone(&runs);
+///     move |_| {
+///         count.get();
+///         runs.set(runs.get() + 1);
+///     }
+/// });
+///
+/// // ✅ updating another field doesn't notify subscribers to `count`
+/// state.update(|state| state.name = "Alice".to_string());
+/// assert_eq!(runs.get(), 1);
+///
+/// set_count(1);
+/// assert_eq!(count.get(), 1);
+/// assert_eq!(state.with(|state| state.count), 1);
+/// assert_eq!(runs.get(), 2);
+/// # }).dispose();
+/// ```
+pub fn create_slice<S, T>(
+    cx: Scope,
+    signal: RwSignal<S>,
+    getter: impl Fn(&S) -> T + 'static,
+    setter: impl Fn(&mut S, T) + Clone + 'static,
+) -> (Memo<T>, impl Fn(T) + Clone)
+where
+    S: 'static,
+    T: PartialEq + Debug + 'static,
+{
+    let getter = create_memo(cx, move |_| signal.with(&getter));
+    let setter = move |value| signal.update(|state| setter(state, value));
+    (getter, setter)
+}
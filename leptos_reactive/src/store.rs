@@ -0,0 +1,383 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
+
+use crate::{create_rw_signal, RwSignal, Scope, SignalError};
+
+/// Creates a store: a reactive value whose fields, and the items of any `Vec` inside it, can be
+/// read and updated as signals of their own.
+///
+/// Reading through a field only subscribes to that field, so updating one field doesn't notify
+/// anything that only read another one. Updating a field notifies anything that read it, anything
+/// nested inside it, and anything that read the whole of the struct that contains it.
+///
+/// Fields are usually accessed with the methods generated by `#[derive(Store)]`, but they can also
+/// be accessed with [StoreField::field].
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::Cell, rc::Rc};
+/// # create_scope(|cx| {
+/// struct Todo {
+///     title: String,
+///     done: bool,
+/// }
+///
+/// let todos = create_store(cx, vec![
+///     Todo { title: "Write code".to_string(), done: false },
+///     Todo { title: "Test code".to_string(), done: false },
+/// ]);
+/// let first_title = todos.at(0).field("title", |todo| &todo.title, |todo| &mut todo.title);
+/// let second_done = todos.at(1).field("done", |todo| &todo.done, |todo| &mut todo.done);
+///
+/// let title_runs = Rc::new(Cell::new(0));
+/// create_isomorphic_effect(cx, {
+///     let title_runs = Rc::clone(&title_runs);
+///     move |_| {
+///         first_title.with(|title| title.len());
+///         title_runs.set(title_runs.get() + 1);
+///     }
+/// });
+/// assert_eq!(title_runs.get(), 1);
+///
+/// // ✅ updating another row doesn't notify the first row's title
+/// second_done.set(true);
+/// assert_eq!(title_runs.get(), 1);
+///
+/// // ✅ but updating the first row does
+/// todos.at(0).update(|todo| todo.title = "Write more code".to_string());
+/// assert_eq!(title_runs.get(), 2);
+/// # }).dispose();
+/// ```
+pub fn create_store<T>(cx: Scope, value: T) -> Store<T>
+where
+    T: 'static,
+{
+    StoreField {
+        store: Rc::new(StoreState {
+            cx,
+            value: RefCell::new(value),
+            triggers: Default::default(),
+        }),
+        path: Vec::new(),
+        get: Rc::new(|value| value),
+        get_mut: Rc::new(|value| value),
+        exists: Rc::new(|_| true),
+    }
+}
+
+/// A reactive store created by [create_store]. The store is itself the [StoreField] for its
+/// whole value.
+pub type Store<T> = StoreField<T, T>;
+
+/// One step in the path from the root of a [Store] to one of its fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StorePathSegment {
+    /// A named field of a struct.
+    Field(&'static str),
+    /// An item in a `Vec`.
+    Index(usize),
+}
+
+/// A part of a [Store] with a value of type `T`, which can be read and updated like a signal.
+///
+/// Reading it subscribes the running effect only to this part of the store.
+pub struct StoreField<S, T>
+where
+    S: 'static,
+    T: 'static,
+{
+    store: Rc<StoreState<S>>,
+    path: Vec<StorePathSegment>,
+    get: Rc<dyn Fn(&S) -> &T>,
+    get_mut: Rc<dyn Fn(&mut S) -> &mut T>,
+    // whether the field is still there, e.g., whether the item at an index is still in its `Vec`
+    exists: Rc<dyn Fn(&S) -> bool>,
+}
+
+struct StoreState<S> {
+    cx: Scope,
+    value: RefCell<S>,
+    // each path that has been read is tracked by its own signal
+    triggers: RefCell<HashMap<Vec<StorePathSegment>, StoreTrigger<S>>>,
+}
+
+struct StoreTrigger<S> {
+    signal: RwSignal<()>,
+    exists: Rc<dyn Fn(&S) -> bool>,
+}
+
+impl<S, T> Clone for StoreField<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: Rc::clone(&self.store),
+            path: self.path.clone(),
+            get: Rc::clone(&self.get),
+            get_mut: Rc::clone(&self.get_mut),
+            exists: Rc::clone(&self.exists),
+        }
+    }
+}
+
+impl<S, T> Debug for StoreField<S, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoreField")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl<S, T> StoreField<S, T>
+where
+    S: 'static,
+    T: 'static,
+{
+    /// Applies a function to the current value of the field, and subscribes the running effect
+    /// to this field.
+    pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        self.try_with(f).unwrap()
+    }
+
+    /// Applies a function to the current value of the field, and subscribes the running effect
+    /// to this field. Returns [SignalError::Disposed], rather than panicking, if the store's
+    /// [Scope] has been disposed or the field no longer exists, like an item past the end of a
+    /// `Vec` that has shrunk.
+    pub fn try_with<U>(&self, f: impl FnOnce(&T) -> U) -> Result<U, SignalError> {
+        if !(self.exists)(&*self.store.value.borrow()) {
+            return Err(SignalError::Disposed);
+        }
+        self.track()?;
+        let value = self.store.value.borrow();
+        Ok(f((self.get)(&*value)))
+    }
+
+    /// Clones and returns the current value of the field, and subscribes the running effect
+    /// to this field.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.with(T::clone)
+    }
+
+    /// Applies a function to the current value to mutate it in place, and notifies subscribers
+    /// to this field, to any of its fields, and to the fields that contain it.
+    ///
+    /// Fields that are no longer there afterward, like the items past the end of a `Vec` that
+    /// has shrunk, are unsubscribed rather than notified.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        self.update_and_notify(f, |_| true)
+    }
+
+    /// Sets the value of the field and notifies subscribers, like [StoreField::update].
+    pub fn set(&self, value: T) {
+        self.update(|n| *n = value)
+    }
+
+    /// Clones and returns the current value of the field, like [StoreField::get]. Returns an
+    /// error, rather than panicking, in the same cases as [StoreField::try_with].
+    pub fn try_get(&self) -> Result<T, SignalError>
+    where
+        T: Clone,
+    {
+        self.try_with(T::clone)
+    }
+
+    /// Returns the path from the root of the store to this field.
+    pub fn path(&self) -> &[StorePathSegment] {
+        &self.path
+    }
+
+    /// Returns a [StoreField] for one of this field's own fields, given its name and functions
+    /// to access it. This is usually called by the methods generated by `#[derive(Store)]`.
+    pub fn field<U>(
+        &self,
+        name: &'static str,
+        get: fn(&T) -> &U,
+        get_mut: fn(&mut T) -> &mut U,
+    ) -> StoreField<S, U> {
+        self.child(StorePathSegment::Field(name), get, get_mut, |_| true)
+    }
+
+    fn child<U>(
+        &self,
+        segment: StorePathSegment,
+        get: impl Fn(&T) -> &U + 'static,
+        get_mut: impl Fn(&mut T) -> &mut U + 'static,
+        exists: impl Fn(&T) -> bool + 'static,
+    ) -> StoreField<S, U> {
+        let mut path = self.path.clone();
+        path.push(segment);
+        let parent_get = Rc::clone(&self.get);
+        let parent_get_mut = Rc::clone(&self.get_mut);
+        let parent_exists = Rc::clone(&self.exists);
+        StoreField {
+            store: Rc::clone(&self.store),
+            path,
+            get: Rc::new({
+                let parent_get = Rc::clone(&parent_get);
+                move |value| get(parent_get(value))
+            }),
+            get_mut: Rc::new(move |value| get_mut(parent_get_mut(value))),
+            exists: Rc::new(move |value| parent_exists(value) && exists(parent_get(value))),
+        }
+    }
+
+    fn track(&self) -> Result<(), SignalError> {
+        let mut triggers = self.store.triggers.borrow_mut();
+        let trigger = match triggers.get(&self.path) {
+            Some(trigger) => trigger.signal,
+            // once the store's scope is gone, there's nowhere to create a new trigger
+            None if self.store.cx.is_disposed() => return Err(SignalError::Disposed),
+            None => {
+                let signal = create_rw_signal(self.store.cx, ());
+                triggers.insert(
+                    self.path.clone(),
+                    StoreTrigger {
+                        signal,
+                        exists: Rc::clone(&self.exists),
+                    },
+                );
+                signal
+            }
+        };
+        drop(triggers);
+        trigger.try_with(|_| ())
+    }
+
+    // Updates the value, then notifies this field, the fields that contain it, and whichever
+    // fields nested inside it `notify_nested` selects, given their paths relative to this one.
+    fn update_and_notify(
+        &self,
+        f: impl FnOnce(&mut T),
+        notify_nested: impl Fn(&[StorePathSegment]) -> bool,
+    ) {
+        {
+            let mut value = self.store.value.borrow_mut();
+            f((self.get_mut)(&mut *value));
+        }
+        self.prune_missing();
+
+        let triggers = self
+            .store
+            .triggers
+            .borrow()
+            .iter()
+            .filter(|(path, _)| {
+                if path.len() <= self.path.len() {
+                    self.path.starts_with(path)
+                } else {
+                    path.starts_with(&self.path) && notify_nested(&path[self.path.len()..])
+                }
+            })
+            .map(|(_, trigger)| trigger.signal)
+            .collect::<Vec<_>>();
+        for trigger in triggers {
+            trigger.update(|_| ());
+        }
+    }
+
+    // Drops the triggers for fields nested inside this one that are no longer there, like the
+    // items past the end of a `Vec` that has shrunk, so that anything that only read them is
+    // unsubscribed rather than notified (reading them again would panic).
+    fn prune_missing(&self) {
+        let pruned = {
+            let value = self.store.value.borrow();
+            let mut triggers = self.store.triggers.borrow_mut();
+            let missing = triggers
+                .iter()
+                .filter(|(path, trigger)| {
+                    path.len() > self.path.len()
+                        && path.starts_with(&self.path)
+                        && !(trigger.exists)(&*value)
+                })
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>();
+            missing
+                .into_iter()
+                .filter_map(|path| triggers.remove(&path))
+                .collect::<Vec<_>>()
+        };
+        for trigger in pruned {
+            trigger.signal.runtime.dispose_signal(trigger.signal.id);
+        }
+    }
+}
+
+impl<S, T> StoreField<S, Vec<T>>
+where
+    S: 'static,
+    T: 'static,
+{
+    /// Returns a [StoreField] for the item at `index`.
+    ///
+    /// The field always refers to whatever item is at `index`, and reading it panics if there is no
+    /// item there.
+    pub fn at(&self, index: usize) -> StoreField<S, T> {
+        self.child(
+            StorePathSegment::Index(index),
+            move |items: &Vec<T>| &items[index],
+            move |items: &mut Vec<T>| &mut items[index],
+            move |items: &Vec<T>| index < items.len(),
+        )
+    }
+
+    /// Returns the number of items, and subscribes the running effect to this field.
+    pub fn len(&self) -> usize {
+        self.with(Vec::len)
+    }
+
+    /// Returns `true` if there are no items, and subscribes the running effect to this field.
+    pub fn is_empty(&self) -> bool {
+        self.with(Vec::is_empty)
+    }
+
+    /// Adds an item to the end. The existing items are unchanged, so only subscribers to the
+    /// whole `Vec` (and the fields that contain it) are notified.
+    pub fn push(&self, item: T) {
+        self.update_and_notify(|items| items.push(item), |_| false)
+    }
+
+    /// Removes and returns the item at `index`. The items after it move down, so subscribers to
+    /// them are notified, but subscribers to the items before it are not.
+    ///
+    /// The last slot no longer holds an item, so anything that only read that slot is
+    /// unsubscribed rather than notified.
+    pub fn remove(&self, index: usize) -> T {
+        let mut removed = None;
+        self.update_and_notify(
+            |items| removed = Some(items.remove(index)),
+            |path| matches!(path.first(), Some(StorePathSegment::Index(i)) if *i >= index),
+        );
+        removed.unwrap()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<S, T> FnOnce<()> for StoreField<S, T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    extern "rust-call" fn call_once(self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<S, T> FnMut<()> for StoreField<S, T>
+where
+    T: Clone,
+{
+    extern "rust-call" fn call_mut(&mut self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(not(feature = "stable"))]
+impl<S, T> Fn<()> for StoreField<S, T>
+where
+    T: Clone,
+{
+    extern "rust-call" fn call(&self, _args: ()) -> Self::Output {
+        self.get()
+    }
+}
@@ -50,4 +50,48 @@ impl SuspenseContext {
             .try_with(|n| *n == 0)
             .unwrap_or(false)
     }
+
+    /// Runs `f` with this as the current boundary. Any [Resource](crate::Resource) read while `f` runs
+    /// will suspend this boundary, rather than one found through context. Nested boundaries take
+    /// precedence over the boundaries around them, so each boundary only waits for its own resources.
+    pub fn run_in<T>(&self, cx: Scope, f: impl FnOnce() -> T) -> T {
+        // pops the boundary even if `f` panics, so it doesn't capture resources read later
+        struct Guard(Scope);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.runtime.suspense_boundaries.borrow_mut().pop();
+            }
+        }
+
+        cx.runtime.suspense_boundaries.borrow_mut().push(*self);
+        let _guard = Guard(cx);
+        f()
+    }
+
+    /// The boundary that is currently rendering, if any.
+    pub(crate) fn current(cx: Scope) -> Option<Self> {
+        cx.runtime.suspense_boundaries.borrow().last().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run_scope;
+
+    #[test]
+    fn boundary_is_popped_after_a_panic() {
+        run_scope(|cx| {
+            let boundary = SuspenseContext::new(cx);
+            let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                boundary.run_in(cx, || {
+                    assert_eq!(SuspenseContext::current(cx), Some(boundary));
+                    panic!("rendering failed");
+                })
+            }));
+            assert!(panicked.is_err());
+            assert_eq!(SuspenseContext::current(cx), None);
+        });
+    }
 }
@@ -28,3 +28,96 @@ fn derived_signals() {
     })
     .dispose()
 }
+
+#[test]
+fn slice_only_notifies_when_its_part_changes() {
+    use leptos_reactive::{create_isomorphic_effect, create_rw_signal, create_scope, create_slice};
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(|cx| {
+        let state = create_rw_signal(cx, (0, String::new()));
+        let (count, set_count) = create_slice(cx, state, |state| state.0, |state, n| state.0 = n);
+
+        let runs = Rc::new(Cell::new(0));
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                count.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+
+        state.update(|state| state.1.push('a'));
+        assert_eq!(runs.get(), 1);
+
+        // setting the same value again doesn't notify either
+        set_count(0);
+        assert_eq!(runs.get(), 1);
+
+        set_count(2);
+        assert_eq!(runs.get(), 2);
+        assert_eq!(state.with(|state| state.0), 2);
+    })
+    .dispose()
+}
+
+#[test]
+fn signal_traits_and_wrappers() {
+    use leptos_reactive::{
+        create_memo, create_rw_signal, create_scope, create_signal, MaybeSignal, Signal, SignalGet,
+        SignalSet, SignalWith,
+    };
+
+    fn total(values: &[&dyn SignalGet<i32>]) -> i32 {
+        values.iter().map(|value| value.get()).sum()
+    }
+
+    fn set_to(signal: &impl SignalSet<i32>, value: i32) {
+        signal.set(value)
+    }
+
+    create_scope(|cx| {
+        let (a, set_a) = create_signal(cx, 1);
+        let b = create_rw_signal(cx, 2);
+        let c = create_memo(cx, move |_| a.get() * 10);
+        let d = Signal::derive(move || b.get() * 100);
+        let e: MaybeSignal<i32> = 1000.into();
+        let f: MaybeSignal<i32> = c.into();
+
+        assert_eq!(total(&[&a, &b, &c, &d, &e, &f]), 1 + 2 + 10 + 200 + 1000 + 10);
+
+        set_to(&set_a, 2);
+        set_to(&b, 3);
+        assert_eq!(total(&[&a, &b, &c, &d, &e, &f]), 2 + 3 + 20 + 300 + 1000 + 20);
+        assert_eq!(SignalWith::with(&d, |d| *d), 300);
+    })
+    .dispose()
+}
+
+#[test]
+fn try_accessors_after_dispose() {
+    use leptos_reactive::{
+        create_memo, create_rw_signal, create_signal, run_scope_undisposed, Signal, SignalError,
+    };
+
+    let ((a, set_a, b, c), _, disposer) = run_scope_undisposed(|cx| {
+        let (a, set_a) = create_signal(cx, 1);
+        let b = create_rw_signal(cx, 2);
+        let c = create_memo(cx, move |_| a.get() + 1);
+        (a, set_a, b, c)
+    });
+
+    assert_eq!(a.try_get(), Ok(1));
+    assert!(set_a.try_set(2));
+    assert_eq!(c.try_get(), Ok(3));
+    assert!(b.try_update(|n| *n += 1));
+    assert_eq!(b.try_get(), Ok(3));
+
+    disposer.dispose();
+
+    assert_eq!(a.try_get(), Err(SignalError::Disposed));
+    assert!(!set_a.try_set(3));
+    assert!(!b.try_update(|n| *n += 1));
+    assert_eq!(b.try_with(|n| *n), Err(SignalError::Disposed));
+    assert_eq!(Signal::from(b).try_get(), Err(SignalError::Disposed));
+}
@@ -0,0 +1,235 @@
+use std::{cell::Cell, rc::Rc};
+
+use leptos_reactive::{
+    create_isomorphic_effect, create_scope, create_store, run_scope_undisposed, SignalError,
+    StoreField,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Todo {
+    title: String,
+    done: bool,
+}
+
+fn title<S>(todo: &StoreField<S, Todo>) -> StoreField<S, String> {
+    todo.field("title", |todo| &todo.title, |todo| &mut todo.title)
+}
+
+fn done<S>(todo: &StoreField<S, Todo>) -> StoreField<S, bool> {
+    todo.field("done", |todo| &todo.done, |todo| &mut todo.done)
+}
+
+fn todos() -> Vec<Todo> {
+    ["A", "B", "C"]
+        .into_iter()
+        .map(|title| Todo {
+            title: title.to_string(),
+            done: false,
+        })
+        .collect()
+}
+
+// counts how many times an effect reading `f` runs
+fn runs(cx: leptos_reactive::Scope, f: impl Fn() + 'static) -> Rc<Cell<usize>> {
+    let runs = Rc::new(Cell::new(0));
+    create_isomorphic_effect(cx, {
+        let runs = Rc::clone(&runs);
+        move |_| {
+            f();
+            runs.set(runs.get() + 1);
+        }
+    });
+    runs
+}
+
+#[test]
+fn store_fields_only_notify_their_own_subscribers() {
+    create_scope(|cx| {
+        let store = create_store(cx, todos());
+        let first_title = runs(cx, {
+            let store = store.clone();
+            move || {
+                title(&store.at(0)).get();
+            }
+        });
+        let first_done = runs(cx, {
+            let store = store.clone();
+            move || {
+                done(&store.at(0)).get();
+            }
+        });
+        let whole = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.with(|_| ());
+            }
+        });
+
+        done(&store.at(0)).set(true);
+        assert_eq!(first_title.get(), 1);
+        assert_eq!(first_done.get(), 2);
+        assert_eq!(whole.get(), 2);
+
+        // updating a row notifies all of its fields
+        store.at(0).set(Todo {
+            title: "D".to_string(),
+            done: false,
+        });
+        assert_eq!(first_title.get(), 2);
+        assert_eq!(first_done.get(), 3);
+        assert_eq!(whole.get(), 3);
+        assert_eq!(title(&store.at(0)).get(), "D");
+    })
+    .dispose()
+}
+
+#[test]
+fn store_vec_structural_updates() {
+    create_scope(|cx| {
+        let store = create_store(cx, todos());
+        let first = runs(cx, {
+            let store = store.clone();
+            move || {
+                title(&store.at(0)).get();
+            }
+        });
+        let last = runs(cx, {
+            let store = store.clone();
+            move || {
+                title(&store.at(2)).get();
+            }
+        });
+        let len = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.len();
+            }
+        });
+
+        store.push(Todo {
+            title: "D".to_string(),
+            done: false,
+        });
+        assert_eq!((first.get(), last.get(), len.get()), (1, 1, 2));
+
+        // the items after the removed one move down
+        assert_eq!(store.remove(1).title, "B");
+        assert_eq!((first.get(), last.get(), len.get()), (1, 2, 3));
+        assert_eq!(title(&store.at(2)).get(), "D");
+        assert_eq!(store.len(), 3);
+    })
+    .dispose()
+}
+
+#[test]
+fn store_vec_remove_last_item() {
+    create_scope(|cx| {
+        let store = create_store(cx, vec![1, 2, 3]);
+        let last = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.at(2).get();
+            }
+        });
+        let len = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.len();
+            }
+        });
+
+        // the slot that's gone isn't notified (reading it would panic)
+        assert_eq!(store.remove(2), 3);
+        assert_eq!((last.get(), len.get()), (1, 2));
+        assert_eq!(store.get(), vec![1, 2]);
+
+        // its trigger was dropped, so later updates don't reach it either
+        store.push(4);
+        store.at(2).set(5);
+        assert_eq!((last.get(), len.get()), (1, 4));
+        assert_eq!(store.get(), vec![1, 2, 5]);
+    })
+    .dispose()
+}
+
+#[test]
+fn store_set_shorter_vec() {
+    create_scope(|cx| {
+        let store = create_store(cx, vec![1, 2, 3]);
+        let last = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.at(2).get();
+            }
+        });
+        let first = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.at(0).get();
+            }
+        });
+
+        // the items past the new end are gone, so they aren't notified (reading them would panic)
+        store.set(vec![10]);
+        assert_eq!((first.get(), last.get()), (2, 1));
+        assert_eq!(store.get(), vec![10]);
+
+        // growing again doesn't reach the old subscribers either
+        store.set(vec![1, 2, 3]);
+        assert_eq!((first.get(), last.get()), (3, 1));
+    })
+    .dispose()
+}
+
+#[test]
+fn store_update_pop() {
+    create_scope(|cx| {
+        let store = create_store(cx, todos());
+        let last_title = runs(cx, {
+            let store = store.clone();
+            move || {
+                title(&store.at(2)).get();
+            }
+        });
+        let len = runs(cx, {
+            let store = store.clone();
+            move || {
+                store.len();
+            }
+        });
+
+        store.update(|todos| {
+            todos.pop();
+        });
+        assert_eq!((last_title.get(), len.get()), (1, 2));
+        assert_eq!(store.len(), 2);
+    })
+    .dispose()
+}
+
+#[test]
+fn store_try_get_after_scope_disposed() {
+    let (store, _, disposer) = run_scope_undisposed(|cx| create_store(cx, todos()));
+    assert_eq!(title(&store.at(0)).try_get(), Ok("A".to_string()));
+
+    disposer.dispose();
+    // both a field that was read before and one that never was
+    assert_eq!(title(&store.at(0)).try_get(), Err(SignalError::Disposed));
+    assert_eq!(title(&store.at(1)).try_get(), Err(SignalError::Disposed));
+}
+
+#[test]
+fn store_try_get_missing_item() {
+    create_scope(|cx| {
+        let store = create_store(cx, todos());
+        let last = store.at(2);
+        assert_eq!(title(&last).try_get(), Ok("C".to_string()));
+
+        store.update(|todos| {
+            todos.pop();
+        });
+        assert_eq!(title(&last).try_get(), Err(SignalError::Disposed));
+        assert_eq!(title(&store.at(5)).try_get(), Err(SignalError::Disposed));
+    })
+    .dispose()
+}
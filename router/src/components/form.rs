@@ -56,12 +56,12 @@ where
     let action_version = version;
     let action = use_resolved_path(cx, move || action.to_href()());
 
-    let on_submit = move |ev: web_sys::Event| {
+    let on_submit = move |ev: web_sys::SubmitEvent| {
         if ev.default_prevented() {
             return;
         }
         ev.prevent_default();
-        let submitter = ev.unchecked_ref::<web_sys::SubmitEvent>().submitter();
+        let submitter = ev.submitter();
         let navigate = use_navigate(cx);
 
         let (form, method, action, enctype) = match &submitter {
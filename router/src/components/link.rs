@@ -6,7 +6,10 @@ use typed_builder::TypedBuilder;
 #[cfg(any(feature = "csr", feature = "hydrate"))]
 use wasm_bindgen::JsCast;
 
-use crate::{use_location, use_resolved_path, State};
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+use crate::use_router;
+
+use crate::{matching::Matcher, use_location, use_resolved_path, Location, State, Url};
 
 /// Describes a value that is either a static or a reactive URL, i.e.,
 /// a [String], a [&str], or a reactive `Fn() -> String`.
@@ -39,6 +42,34 @@ where
     }
 }
 
+/// Describes when an [A] component should prefetch the route it links to, rendering
+/// it ahead of time so that its resources begin loading before the user navigates.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Prefetch {
+    /// Never prefetch the route.
+    #[default]
+    None,
+    /// Prefetch the route when the user shows an intent to navigate, by hovering over,
+    /// focusing, or touching the link.
+    Intent,
+    /// Prefetch the route as soon as the link is rendered.
+    Render,
+}
+
+impl From<&str> for Prefetch {
+    fn from(value: &str) -> Self {
+        match value {
+            "intent" => Prefetch::Intent,
+            "render" => Prefetch::Render,
+            "none" => Prefetch::None,
+            _ => {
+                debug_warn!("[Link] Unknown prefetch value {value:?}; expected \"intent\", \"render\", or \"none\".");
+                Prefetch::None
+            }
+        }
+    }
+}
+
 /// Properties that can be passed to the [A] component, which is an HTML
 /// [`a`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/a)
 /// progressively enhanced to use client-side routing.
@@ -52,9 +83,15 @@ where
     /// to the current route.
     pub href: H,
     /// If `true`, the link is marked active when the location matches exactly;
-    /// if false, link is marked active if the current route starts with it.
+    /// if false, link is marked active if the current route starts with it, segment by segment
+    /// (so a link to `/users` is active at `/users/1`, but not at `/users-admin`). The link's path
+    /// is matched the same way as a route's, including `:params` and `*` splats.
     #[builder(default)]
     pub exact: bool,
+    /// If `true`, each of the query parameters in the link must also be present in the
+    /// current location for the link to be active. By default, the query is ignored.
+    #[builder(default)]
+    pub match_query: bool,
     /// An object of any type that will be pushed to router state
     #[builder(default, setter(strip_option))]
     pub state: Option<State>,
@@ -62,6 +99,17 @@ where
     /// will skip this page.)
     #[builder(default)]
     pub replace: bool,
+    /// Whether the route the link points to should be prefetched, either
+    /// `"intent"` (on hover or focus), `"render"` (as soon as the link is rendered),
+    /// or `"none"` (the default).
+    #[builder(default, setter(into))]
+    pub prefetch: Prefetch,
+    /// A class that will be added to the link while it is active.
+    #[builder(default, setter(strip_option, into))]
+    pub active_class: Option<String>,
+    /// A class that will be added to the link while it is not active.
+    #[builder(default, setter(strip_option, into))]
+    pub inactive_class: Option<String>,
     /// The nodes or elements to be shown inside the link.
     pub children: Box<dyn Fn() -> Vec<C>>,
 }
@@ -78,21 +126,18 @@ where
     let href = use_resolved_path(cx, move || props.href.to_href()());
     let is_active = create_memo(cx, move |_| match href() {
         None => false,
-
-        Some(to) => {
-            let path = to
-                .split(['?', '#'])
-                .next()
-                .unwrap_or_default()
-                .to_lowercase();
-            let loc = location.pathname.get().to_lowercase();
-            if props.exact {
-                loc == path
+        Some(to) => is_active_link(&location, &to, props.exact, props.match_query),
+    });
+    let class = {
+        let (active_class, inactive_class) = (props.active_class, props.inactive_class);
+        move || {
+            if is_active() {
+                active_class.clone()
             } else {
-                loc.starts_with(&path)
+                inactive_class.clone()
             }
         }
-    });
+    };
 
     let mut children = (props.children)();
     if children.len() != 1 {
@@ -102,21 +147,53 @@ where
 
     cfg_if! {
         if #[cfg(any(feature = "csr", feature = "hydrate"))] {
-            view! { cx,
+            let el = view! { cx,
                 <a
                     href=move || href().unwrap_or_default()
                     prop:state={props.state.map(|s| s.to_js_value())}
                     prop:replace={props.replace}
                     aria-current=move || if is_active() { Some("page") } else { None }
+                    class=move || class()
                 >
                     {child}
                 </a>
+            };
+
+            let router = use_router(cx);
+            let prefetch = move || {
+                if let Some(to) = href() {
+                    router.prefetch(&to);
+                }
+            };
+            match props.prefetch {
+                Prefetch::None => {}
+                Prefetch::Intent => {
+                    leptos_dom::add_event_listener(&el, "mouseover", {
+                        let prefetch = prefetch.clone();
+                        move |_| prefetch()
+                    });
+                    leptos_dom::add_event_listener(&el, "touchstart", {
+                        let prefetch = prefetch.clone();
+                        move |_| prefetch()
+                    });
+                    leptos_dom::add_event_listener_undelegated(cx, &el, "focus", move |_| prefetch());
+                }
+                Prefetch::Render => {
+                    // wait until the current render (or hydration) is finished
+                    create_effect(cx, move |_| {
+                        href();
+                        queue_microtask(prefetch.clone());
+                    });
+                }
             }
+
+            el
         } else {
             view! { cx,
                 <a
                     href=move || href().unwrap_or_default()
                     aria-current=move || if is_active() { Some("page") } else { None }
+                    class=move || class()
                 >
                     {child}
                 </a>
@@ -124,3 +201,31 @@ where
         }
     }
 }
+
+/// Whether a link to the resolved path `to` should be considered active at the current location.
+fn is_active_link(location: &Location, to: &str, exact: bool, match_query: bool) -> bool {
+    let to = to.split('#').next().unwrap_or_default();
+    let (path, search) = to.split_once('?').unwrap_or((to, ""));
+
+    // match the link the same way a route would, so params, splats and trailing slashes
+    // are handled consistently with the router
+    let matcher = Matcher::new_with_partial(&path.to_lowercase(), !exact);
+    let path_matches = location
+        .pathname
+        .with(|loc| matcher.test(&loc.to_lowercase()).is_some());
+
+    path_matches
+        && (!match_query || search.is_empty() || {
+            let query = Url {
+                search: search.to_string(),
+                ..Default::default()
+            }
+            .search_params();
+            location.query.with(|current| {
+                query
+                    .0
+                    .iter()
+                    .all(|(key, value)| current.get(key) == Some(value))
+            })
+        })
+}
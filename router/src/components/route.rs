@@ -1,4 +1,4 @@
-use std::{borrow::Cow, rc::Rc};
+use std::{any::Any, borrow::Cow, cell::RefCell, rc::Rc};
 
 use leptos::*;
 use typed_builder::TypedBuilder;
@@ -25,6 +25,17 @@ where
     /// that takes a [Scope] and returns an [Element] (like `|cx| view! { cx, <p>"Show this"</p> })`
     /// or `|cx| view! { cx, <MyComponent/>` } or even, for a component with no props, `MyComponent`).
     pub element: F,
+    /// If `true`, the route's reactive scope and rendered view are kept when the user navigates
+    /// away from it, and shown again (rather than rendered again) when the user returns.
+    ///
+    /// Only the most recently shown view of each route is kept, and it lives until the user
+    /// returns to the route or the [Routes](crate::Routes) are removed.
+    #[builder(default)]
+    pub keep_alive: bool,
+    /// Any data you want to associate with the route (like a breadcrumb label, or the
+    /// permissions needed to view it), which can be read back from [use_matches](crate::use_matches).
+    #[builder(default, setter(strip_option))]
+    pub meta: Option<Rc<dyn Any>>,
     /// `children` may be empty or include nested routes.
     #[builder(default, setter(strip_option))]
     pub children: Option<Box<dyn Fn() -> Vec<RouteDefinition>>>,
@@ -42,6 +53,8 @@ where
         path: props.path,
         children: props.children.map(|c| c()).unwrap_or_default(),
         element: Rc::new(move |cx| (props.element)(cx).into_child(cx)),
+        keep_alive: props.keep_alive,
+        meta: props.meta,
     }
 }
 
@@ -57,18 +70,25 @@ impl RouteContext {
         router: &RouterContext,
         child: impl Fn() -> Option<RouteContext> + 'static,
         matcher: impl Fn() -> Option<RouteMatch> + 'static,
+        keep_rendered: bool,
     ) -> Option<Self> {
         let base = router.base();
         let base = base.path();
         let RouteMatch { path_match, route } = matcher()?;
         let PathMatch { path, .. } = path_match;
         let RouteDefinition { element, .. } = route.key;
-        let params = create_memo(cx, move |_| {
+        // a route that isn't matched right now (because it's being kept alive) keeps its last params
+        let params = create_memo(cx, move |prev: Option<&ParamsMap>| {
             matcher()
                 .map(|matched| matched.path_match.params)
+                .or_else(|| prev.cloned())
                 .unwrap_or_default()
         });
 
+        // a route that is kept alive or was rendered ahead of time keeps the view it rendered,
+        // so that it can be shown again without rendering it again; others render a new view
+        let rendered: RefCell<Option<Child>> = RefCell::new(None);
+
         Some(Self {
             inner: Rc::new(RouteContextInner {
                 cx,
@@ -77,7 +97,16 @@ impl RouteContext {
                 path,
                 original_path: route.original_path.to_string(),
                 params,
-                outlet: Box::new(move || Some(element(cx))),
+                outlet: Box::new(move || {
+                    if let Some(child) = rendered.borrow().as_ref() {
+                        return Some(child.clone());
+                    }
+                    let child = element(cx);
+                    if keep_rendered {
+                        *rendered.borrow_mut() = Some(child.clone());
+                    }
+                    Some(child)
+                }),
             }),
         })
     }
@@ -13,7 +13,9 @@ use wasm_bindgen::JsCast;
 use leptos_reactive::use_transition;
 
 use crate::{
-    create_location, matching::resolve_path, History, Location, LocationChange, RouteContext,
+    create_location,
+    matching::{resolve_path, RouteMatch},
+    History, Location, LocationChange, RouteContext,
     RouterIntegrationContext, State,
 };
 
@@ -62,8 +64,13 @@ pub(crate) struct RouterContextInner {
     referrers: Rc<RefCell<Vec<LocationChange>>>,
     state: ReadSignal<State>,
     set_state: WriteSignal<State>,
+    pub(crate) prefetcher: RefCell<Option<Prefetcher>>,
+    pub(crate) matches: RwSignal<Vec<RouteMatch>>,
 }
 
+/// Renders the routes matching a path ahead of time. Set by [Routes](crate::Routes).
+pub(crate) type Prefetcher = Rc<dyn Fn(&str)>;
+
 impl std::fmt::Debug for RouterContextInner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RouterContextInner")
@@ -76,6 +83,8 @@ impl std::fmt::Debug for RouterContextInner {
             .field("referrers", &self.referrers)
             .field("state", &self.state)
             .field("set_state", &self.set_state)
+            .field("prefetcher", &self.prefetcher.borrow().is_some())
+            .field("matches", &self.matches)
             .finish()
     }
 }
@@ -157,15 +166,17 @@ impl RouterContext {
             referrers,
             state,
             set_state,
+            prefetcher: Default::default(),
+            matches: create_rw_signal(cx, Vec::new()),
         });
 
         // handle all click events on anchor tags
+        // (the listener is removed when the router's scope is disposed)
         #[cfg(not(feature = "ssr"))]
-        leptos_dom::window_event_listener("click", {
+        leptos_dom::window_event_listener(cx, "click", {
             let inner = Rc::clone(&inner);
             move |ev| inner.clone().handle_anchor_click(ev)
         });
-        // TODO on_cleanup remove event listener
 
         Self { inner }
     }
@@ -179,6 +190,19 @@ impl RouterContext {
     pub fn base(&self) -> RouteContext {
         self.inner.base.clone()
     }
+
+    /// Renders the routes that match the given path ahead of time, so that any resources
+    /// they create start loading before the user navigates there. If the app navigates to
+    /// the same path shortly afterward, the prefetched routes are reused rather than
+    /// rendered again.
+    ///
+    /// Does nothing if there is no [Routes](crate::Routes) component beneath the router.
+    pub fn prefetch(&self, path: &str) {
+        let prefetcher = self.inner.prefetcher.borrow().clone();
+        if let Some(prefetcher) = prefetcher {
+            prefetcher(path);
+        }
+    }
 }
 
 impl RouterContextInner {
@@ -1,9 +1,9 @@
-use std::{cmp::Reverse, rc::Rc, cell::{RefCell, Cell}, ops::IndexMut};
+use std::{cmp::Reverse, rc::Rc, cell::{RefCell, Cell}, collections::HashMap, ops::IndexMut, time::Duration};
 
 use leptos::*;
 use typed_builder::TypedBuilder;
 
-use crate::{matching::{expand_optionals, join_paths, Branch, Matcher, RouteDefinition, get_route_matches, RouteMatch}, RouterContext, RouteContext};
+use crate::{matching::{expand_optionals, join_paths, Branch, Matcher, RouteDefinition, get_route_matches, RouteMatch}, RouterContext, RouterContextInner, RouteContext};
 
 /// Props for the [Routes] component, which contains route definitions and manages routing.
 #[derive(TypedBuilder)]
@@ -34,11 +34,34 @@ pub fn Routes(cx: Scope, props: RoutesProps) -> impl IntoChild {
     // whenever path changes, update matches
     let matches = create_memo(cx, {
         let router = router.clone();
+        let branches = branches.clone();
         move |_| {
             get_route_matches(branches.clone(), router.pathname().get())
         }
     });
 
+    // share the matches with the router, so they're available outside the <Routes/>
+    create_isomorphic_effect(cx, {
+        let router = Rc::clone(&router.inner);
+        move |_| router.matches.set(matches())
+    });
+
+    // routes rendered ahead of time by `RouterContext::prefetch`
+    let prefetched: Rc<RefCell<PrefetchCache>> = Default::default();
+    *router.inner.prefetcher.borrow_mut() = Some(Rc::new({
+        let router = Rc::clone(&router.inner);
+        let prefetched = Rc::clone(&prefetched);
+        move |path| prefetch_routes(cx, &router, &branches, matches, &prefetched, path)
+    }));
+    on_cleanup(cx, {
+        let router = Rc::clone(&router.inner);
+        let prefetched = Rc::clone(&prefetched);
+        move || {
+            router.prefetcher.borrow_mut().take();
+            prefetched.borrow_mut().entries.clear();
+        }
+    });
+
     // Rebuild the list of nested routes conservatively, and show the root route here
     let mut disposers = Vec::<ScopeDisposer>::new();
 
@@ -46,6 +69,9 @@ pub fn Routes(cx: Scope, props: RoutesProps) -> impl IntoChild {
     // and replacing them with new routes when they differ
     let next: Rc<RefCell<Vec<RouteContext>>> = Default::default();
 
+    // routes with `keep_alive` that the user has navigated away from
+    let mut kept_alive = KeptAlive::default();
+
     let root_equal = Rc::new(Cell::new(true));
 
     let route_states: Memo<RouterState> = create_memo(cx, {
@@ -54,6 +80,10 @@ pub fn Routes(cx: Scope, props: RoutesProps) -> impl IntoChild {
             root_equal.set(true);
             next.borrow_mut().clear();
 
+            // routes that were prefetched for this path can be used instead of rendering new ones
+            let mut prefetched = cx.untrack(|| prefetched.borrow_mut().take(&router.pathname().get()));
+            let mut adopted = Vec::new();
+
             let next_matches = matches();
             let prev_matches = prev.as_ref().map(|p| &p.matches);
             let prev_routes = prev.as_ref().map(|p| &p.routes);
@@ -81,52 +111,91 @@ pub fn Routes(cx: Scope, props: RoutesProps) -> impl IntoChild {
                         root_equal.set(false);
                     }
 
-                    let disposer = cx.child_scope({
-                        let next = next.clone();
-                        let router = Rc::clone(&router.inner);
-                        move |cx| {
+                    // a route that was kept alive or prefetched can be shown instead of rendering a new one
+                    let reused = kept_alive.take(i, &next_match.route.key).or_else(|| {
+                        prefetched
+                            .as_mut()
+                            .and_then(|prefetched| prefetched.adopt(i, next_match))
+                            .map(|PrefetchedRoute { context, disposer, set_adopted, .. }| {
+                                adopted.push(set_adopted);
+                                (context, disposer)
+                            })
+                    });
+                    let disposer = if let Some((context, disposer)) = reused {
+                        if next.borrow().len() > i {
+                            next.borrow_mut()[i] = context;
+                        } else {
+                            next.borrow_mut().push(context);
+                        }
+                        disposer
+                    } else {
+                        cx.child_scope({
                             let next = next.clone();
-                            let next_ctx = RouteContext::new(
-                                cx,
-                                &RouterContext { inner: router },
-                                {
-                                    let next = next.clone();
-                                    move || {
-                                        if let Some(route_states) = use_context::<Memo<RouterState>>(cx) {
-                                            route_states.with(|route_states| {
-                                                let routes = route_states.routes.borrow();
-                                                routes.get(i + 1).cloned()
-                                            })
-                                        } else {
-                                            next.borrow().get(i + 1).cloned()
+                            let router = Rc::clone(&router.inner);
+                            let key = next_match.route.key.clone();
+                            let keep_alive = key.keep_alive;
+                            move |cx| {
+                                let next = next.clone();
+                                let next_ctx = RouteContext::new(
+                                    cx,
+                                    &RouterContext { inner: router },
+                                    {
+                                        let next = next.clone();
+                                        let key = key.clone();
+                                        move || {
+                                            if let Some(route_states) = use_context::<Memo<RouterState>>(cx) {
+                                                child_route(route_states, i, &key)
+                                            } else {
+                                                next.borrow().get(i + 1).cloned()
+                                            }
                                         }
-                                    }
-                                },
-                                move || {
-                                    matches().get(i).cloned()
-                                }
-                            );
+                                    },
+                                    move || {
+                                        matches().get(i).filter(|m| m.route.key == key).cloned()
+                                    },
+                                    keep_alive,
+                                );
 
-                            if let Some(next_ctx) = next_ctx {
-                                if next.borrow().len() > i + 1 {
-                                    next.borrow_mut()[i] = next_ctx;
-                                } else {
-                                    next.borrow_mut().push(next_ctx);
+                                if let Some(next_ctx) = next_ctx {
+                                    if next.borrow().len() > i {
+                                        next.borrow_mut()[i] = next_ctx;
+                                    } else {
+                                        next.borrow_mut().push(next_ctx);
+                                    }
                                 }
                             }
-                        }
-                    });
+                        })
+                    };
 
-                    if disposers.len() > i + 1 {
+                    if disposers.len() > i {
                         let old_route_disposer = std::mem::replace(&mut disposers[i], disposer);
-                        old_route_disposer.dispose();
+                        kept_alive.retire(i, prev_matches, prev_routes, old_route_disposer);
                     } else {
                         disposers.push(disposer);
                     }
                 }
             }
 
-            // TODO dispose of extra routes from previous matches if they're longer than new ones
+            // dispose of extra routes from previous matches if they're longer than new ones
+            if disposers.len() > next_matches.len() {
+                let extra = disposers.split_off(next_matches.len());
+                for (i, old_route_disposer) in extra.into_iter().enumerate() {
+                    kept_alive.retire(next_matches.len() + i, prev_matches, prev_routes, old_route_disposer);
+                }
+            }
+
+            // prefetched routes that weren't needed (because the old ones were kept) can go
+            if let Some(prefetched) = prefetched {
+                prefetched.dispose();
+            }
+            // adopted routes switch over to following the live matches once this update is done
+            if !adopted.is_empty() {
+                queue_microtask(move || {
+                    for set_adopted in adopted {
+                        set_adopted.update(|adopted| *adopted = true);
+                    }
+                });
+            }
 
             if let Some(prev) = &prev && equal {
                 RouterState {
@@ -164,6 +233,254 @@ pub fn Routes(cx: Scope, props: RoutesProps) -> impl IntoChild {
     })
 }
 
+/// How long routes rendered by [RouterContext::prefetch] are kept around, waiting to be navigated to.
+const PREFETCH_TTL: Duration = Duration::from_secs(10);
+
+#[derive(Default)]
+struct PrefetchCache {
+    next_id: usize,
+    entries: HashMap<String, PrefetchEntry>,
+}
+
+struct PrefetchEntry {
+    id: usize,
+    routes: Vec<(usize, PrefetchedRoute)>,
+}
+
+struct PrefetchedRoute {
+    route_match: RouteMatch,
+    context: RouteContext,
+    disposer: ScopeDisposer,
+    set_adopted: WriteSignal<bool>,
+}
+
+impl PrefetchCache {
+    /// Stores routes prefetched for `path`, returning the id that [PrefetchCache::expire] needs.
+    fn insert(&mut self, path: &str, routes: Vec<(usize, PrefetchedRoute)>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.insert(path.to_string(), PrefetchEntry { id, routes });
+        id
+    }
+
+    /// Takes the routes prefetched for `path`, so that a navigation can adopt them.
+    fn take(&mut self, path: &str) -> Option<PrefetchEntry> {
+        self.entries.remove(path)
+    }
+
+    /// Removes the entry for `path` once its TTL is up, unless it has already been taken
+    /// (and maybe prefetched again since).
+    fn expire(&mut self, path: &str, id: usize) -> Option<PrefetchEntry> {
+        match self.entries.get(path) {
+            Some(entry) if entry.id == id => self.entries.remove(path),
+            _ => None,
+        }
+    }
+}
+
+impl PrefetchEntry {
+    fn adopt(&mut self, index: usize, route_match: &RouteMatch) -> Option<PrefetchedRoute> {
+        let pos = self
+            .routes
+            .iter()
+            .position(|(i, route)| *i == index && route.route_match == *route_match)?;
+        Some(self.routes.remove(pos).1)
+    }
+
+    fn dispose(self) {
+        for (_, route) in self.routes {
+            route.disposer.dispose();
+        }
+    }
+}
+
+fn prefetch_routes(
+    cx: Scope,
+    router: &Rc<RouterContextInner>,
+    branches: &[Branch],
+    matches: Memo<Vec<RouteMatch>>,
+    cache: &Rc<RefCell<PrefetchCache>>,
+    path: &str,
+) {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    if cache.borrow().entries.contains_key(path) {
+        return;
+    }
+
+    cx.untrack(|| {
+        // only render the routes that aren't already being shown
+        let target = get_route_matches(branches.to_vec(), path.to_string());
+        let current = matches.get();
+        let start = match (0..target.len()).find(|&i| {
+            current
+                .get(i)
+                .map(|current| current.route.key != target[i].route.key)
+                .unwrap_or(true)
+        }) {
+            Some(start) => start,
+            None => return,
+        };
+
+        // create route contexts from the innermost out, so that each can find its child
+        let mut routes = Vec::new();
+        let mut child: Option<RouteContext> = None;
+        for i in (start..target.len()).rev() {
+            let route_match = target[i].clone();
+            let key = route_match.route.key.clone();
+            let mut prefetched = None;
+            let disposer = cx.child_scope(|cx| {
+                // until the route is adopted by a navigation, it shows the prefetched match
+                let (adopted, set_adopted) = create_signal(cx, false);
+                let context = RouteContext::new(
+                    cx,
+                    &RouterContext {
+                        inner: Rc::clone(router),
+                    },
+                    {
+                        let child = child.clone();
+                        let key = key.clone();
+                        move || {
+                            if adopted() {
+                                use_context::<Memo<RouterState>>(cx).and_then(|route_states| {
+                                    child_route(route_states, i, &key)
+                                })
+                            } else {
+                                child.clone()
+                            }
+                        }
+                    },
+                    {
+                        let route_match = route_match.clone();
+                        move || {
+                            if adopted() {
+                                matches().get(i).filter(|m| m.route.key == key).cloned()
+                            } else {
+                                Some(route_match.clone())
+                            }
+                        }
+                    },
+                    true,
+                );
+                if let Some(context) = context {
+                    provide_context(cx, context.clone());
+                    prefetched = Some((context, set_adopted));
+                }
+            });
+
+            match prefetched {
+                Some((context, set_adopted)) => {
+                    child = Some(context.clone());
+                    routes.push((
+                        i,
+                        PrefetchedRoute {
+                            route_match,
+                            context,
+                            disposer,
+                            set_adopted,
+                        },
+                    ));
+                }
+                None => {
+                    disposer.dispose();
+                    return;
+                }
+            }
+        }
+        routes.reverse();
+
+        // render each route, which starts loading any resources it creates
+        for (_, route) in &routes {
+            _ = route.context.outlet();
+        }
+
+        let id = cache.borrow_mut().insert(path, routes);
+
+        // if nobody navigates to the path in time, throw the prefetched routes away
+        // (on the server, they're thrown away along with the <Routes/> instead)
+        if cfg!(any(feature = "csr", feature = "hydrate")) {
+            let cache = Rc::clone(cache);
+            let path = path.to_string();
+            set_timeout(
+                move || {
+                    let expired = cache.borrow_mut().expire(&path, id);
+                    if let Some(expired) = expired {
+                        expired.dispose();
+                    }
+                },
+                PREFETCH_TTL,
+            );
+        }
+    })
+}
+
+/// The nested child of the route at index `i`, as long as that route is still the one matched there.
+fn child_route(route_states: Memo<RouterState>, i: usize, key: &RouteDefinition) -> Option<RouteContext> {
+    route_states.with(|route_states| {
+        if route_states.matches.get(i).map(|m| m.route.key == *key).unwrap_or(false) {
+            route_states.routes.borrow().get(i + 1).cloned()
+        } else {
+            None
+        }
+    })
+}
+
+/// Routes with `keep_alive` that the user has navigated away from, which are kept until the
+/// user returns to them or the [Routes] are disposed (as their scopes are children of its scope).
+#[derive(Default)]
+struct KeptAlive {
+    routes: Vec<KeptAliveRoute>,
+}
+
+struct KeptAliveRoute {
+    index: usize,
+    key: RouteDefinition,
+    context: RouteContext,
+    disposer: ScopeDisposer,
+}
+
+impl KeptAlive {
+    /// Takes back a route that was kept alive at the given index, if any.
+    fn take(&mut self, index: usize, key: &RouteDefinition) -> Option<(RouteContext, ScopeDisposer)> {
+        let pos = self
+            .routes
+            .iter()
+            .position(|route| route.index == index && route.key == *key)?;
+        let route = self.routes.remove(pos);
+        Some((route.context, route.disposer))
+    }
+
+    /// Called when the route at the given index is no longer shown: keeps it alive if the route
+    /// asked for that, and disposes of it otherwise.
+    fn retire(
+        &mut self,
+        index: usize,
+        prev_matches: Option<&Vec<RouteMatch>>,
+        prev_routes: Option<&Rc<RefCell<Vec<RouteContext>>>>,
+        disposer: ScopeDisposer,
+    ) {
+        let key = prev_matches
+            .and_then(|matches| matches.get(index))
+            .map(|m| &m.route.key)
+            .filter(|key| key.keep_alive);
+        let context = prev_routes.and_then(|routes| routes.borrow().get(index).cloned());
+        match (key, context) {
+            (Some(key), Some(context)) => {
+                // only one view of each route is kept, so the cache can't outgrow the route definitions
+                if let Some((_, stale)) = self.take(index, key) {
+                    stale.dispose();
+                }
+                self.routes.push(KeptAliveRoute {
+                    index,
+                    key: key.clone(),
+                    context,
+                    disposer,
+                })
+            }
+            _ => disposer.dispose(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 struct RouterState {
     matches: Vec<RouteMatch>,
@@ -253,3 +570,28 @@ fn create_routes(route_def: &RouteDefinition, base: &str) -> Vec<RouteData> {
     }
     acc
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefetched_routes_expire_unless_taken_first() {
+        let mut cache = PrefetchCache::default();
+
+        // a navigation takes the prefetched routes before their TTL is up,
+        // so the timer finds nothing to throw away
+        let first = cache.insert("/about", Vec::new());
+        assert!(cache.take("/about").is_some());
+        assert!(cache.expire("/about", first).is_none());
+
+        // if the path is prefetched again, the old timer leaves the new routes alone...
+        let second = cache.insert("/about", Vec::new());
+        assert!(cache.expire("/about", first).is_none());
+        assert!(cache.entries.contains_key("/about"));
+
+        // ...until their own TTL is up
+        assert!(cache.expire("/about", second).is_some());
+        assert!(cache.take("/about").is_none());
+    }
+}
@@ -54,7 +54,7 @@ impl History for BrowserIntegration {
 
         let (location, set_location) = create_signal(cx, Self::current());
 
-        leptos_dom::window_event_listener("popstate", move |_| {
+        leptos_dom::window_event_listener(cx, "popstate", move |_| {
             log::debug!(
                 "[BrowserIntegration::location] popstate fired {:#?}",
                 Self::current()
@@ -38,16 +38,32 @@ impl ParamsMap {
     #[cfg(any(feature = "csr", feature = "hydrate", feature = "ssr"))]
     /// Converts the map to a query string.
     pub fn to_query_string(&self) -> String {
-        use crate::history::url::escape;
         let mut buf = String::from("?");
-        for (k, v) in &self.0 {
-            buf.push_str(&escape(k));
-            buf.push('=');
-            buf.push_str(&escape(v));
+        for pair in self.query_pairs() {
+            buf.push_str(&pair);
             buf.push('&');
         }
         buf
     }
+
+    /// Converts the map to the search part of a URL, like `?a=1&b=2`, or an empty string if
+    /// the map is empty.
+    pub(crate) fn to_search(&self) -> String {
+        let pairs = self.query_pairs().collect::<Vec<_>>();
+        if pairs.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", pairs.join("&"))
+        }
+    }
+
+    // each key and value escaped and joined as `key=value`, in the order of the map
+    fn query_pairs(&self) -> impl Iterator<Item = String> + '_ {
+        use crate::history::url::escape;
+        self.0
+            .iter()
+            .map(|(k, v)| format!("{}={}", escape(k), escape(v)))
+    }
 }
 
 impl Default for ParamsMap {
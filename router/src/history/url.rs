@@ -73,7 +73,12 @@ impl TryFrom<&str> for Url {
     type Error = String;
 
     fn try_from(url: &str) -> Result<Self, Self::Error> {
-        let url = url::Url::parse(url).map_err(|e| e.to_string())?;
+        // like in the browser, paths (e.g., after navigating on the server) are relative to a fake host
+        let fake_host = url::Url::parse("http://leptos").unwrap();
+        let url = url::Url::options()
+            .base_url(Some(&fake_host))
+            .parse(url)
+            .map_err(|e| e.to_string())?;
         Ok(Self {
             origin: url.origin().unicode_serialization(),
             pathname: url.path().to_string(),
@@ -1,9 +1,9 @@
-use std::rc::Rc;
+use std::{any::Any, cell::Cell, rc::Rc, str::FromStr, time::Duration};
 
-use leptos::{create_memo, use_context, Memo, Scope};
+use leptos::{create_isomorphic_effect, create_memo, create_signal, set_timeout, use_context, Memo, Scope};
 
 use crate::{
-    Location, NavigateOptions, NavigationError, Params, ParamsError, ParamsMap, RouteContext,
+    matching::RouteMatch, Location, NavigateOptions, NavigationError, Params, ParamsError, ParamsMap, RouteContext,
     RouterContext,
 };
 
@@ -58,6 +58,157 @@ where
     })
 }
 
+/// Returns a reactive getter and a setter for a single value in the URL search query.
+///
+/// The getter returns `None` if the key is missing or can't be parsed as a `T`. Calling
+/// the setter with `Some(value)` sets the key in the query string, and `None` removes it.
+/// The new URL replaces the current entry in the browser history.
+///
+/// This makes it easy to keep things like search, filter, or pagination state in the URL:
+/// ```rust,ignore
+/// let (page, set_page) = use_query_signal::<usize>(cx, "page");
+/// let next_page = move |_| set_page(Some(page().unwrap_or(1) + 1));
+/// ```
+pub fn use_query_signal<T>(
+    cx: Scope,
+    key: &'static str,
+) -> (Memo<Option<T>>, impl Fn(Option<T>) + Clone)
+where
+    T: FromStr + ToString + Clone + PartialEq + std::fmt::Debug + 'static,
+{
+    use_query_signal_with_options(cx, key, QuerySignalOptions::default())
+}
+
+/// Like [use_query_signal], but allows you to configure how the URL is updated.
+pub fn use_query_signal_with_options<T>(
+    cx: Scope,
+    key: &'static str,
+    options: QuerySignalOptions,
+) -> (Memo<Option<T>>, impl Fn(Option<T>) + Clone)
+where
+    T: FromStr + ToString + Clone + PartialEq + std::fmt::Debug + 'static,
+{
+    query_signal(cx, key, options, set_timeout)
+}
+
+// Takes the function that schedules debounced updates, so that tests can run them.
+fn query_signal<T>(
+    cx: Scope,
+    key: &'static str,
+    options: QuerySignalOptions,
+    set_timeout: impl Fn(Box<dyn FnOnce()>, Duration) + Clone + 'static,
+) -> (Memo<Option<T>>, impl Fn(Option<T>) + Clone)
+where
+    T: FromStr + ToString + Clone + PartialEq + std::fmt::Debug + 'static,
+{
+    let router = use_router(cx);
+    let location = router.inner.location.clone();
+    let query = location.query;
+    let parse = move |query: &ParamsMap| query.get(key).and_then(|value| T::from_str(value).ok());
+
+    // the debounced update that hasn't reached the URL yet, if any
+    let pending = Rc::new(Cell::new(None::<usize>));
+
+    // the value is updated immediately when it's set, even if updating the URL is debounced
+    let (value, set_value) = create_signal(cx, query.with(parse));
+    create_isomorphic_effect(cx, {
+        let pending = Rc::clone(&pending);
+        move |_| {
+            let from_url = query.with(parse);
+            // a value that was just set shouldn't be overwritten by the URL it's about to replace
+            if pending.get().is_none() {
+                set_value.update(|value| *value = from_url);
+            }
+        }
+    });
+    let value = create_memo(cx, move |_| value.get());
+
+    let generation = Rc::new(Cell::new(0_usize));
+    let set = move |new_value: Option<T>| {
+        set_value.update(|value| *value = new_value.clone());
+
+        let update_url = {
+            let router = router.clone();
+            let location = location.clone();
+            let replace = options.replace;
+            move || {
+                let next = cx.untrack(|| {
+                    let mut query = location.query.get();
+                    let serialized = new_value.map(|value| value.to_string());
+                    // don't add a history entry if the URL already has this value
+                    if query.get(key) == serialized.as_ref() {
+                        return None;
+                    }
+                    match serialized {
+                        Some(value) => query.insert(key.to_string(), value),
+                        None => query.remove(key),
+                    };
+                    Some((
+                        format!("{}{}{}", location.pathname.get(), query.to_search(), location.hash.get()),
+                        location.state.get(),
+                    ))
+                });
+                let Some((to, state)) = next else {
+                    return;
+                };
+                if let Err(e) = Rc::clone(&router.inner).navigate_from_route(
+                    &to,
+                    &NavigateOptions {
+                        resolve: false,
+                        replace,
+                        scroll: false,
+                        state,
+                    },
+                ) {
+                    leptos::leptos_dom::debug_warn!("[use_query_signal] {e}");
+                }
+            }
+        };
+
+        match options.debounce {
+            None => update_url(),
+            Some(delay) => {
+                // only the last of several quick changes actually updates the URL
+                let id = generation.get() + 1;
+                generation.set(id);
+                pending.set(Some(id));
+                let pending = Rc::clone(&pending);
+                set_timeout(
+                    Box::new(move || {
+                        if pending.get() == Some(id) {
+                            pending.set(None);
+                            update_url();
+                        }
+                    }),
+                    delay,
+                );
+            }
+        }
+    };
+
+    (value, set)
+}
+
+/// Options that can be used to configure [use_query_signal_with_options].
+#[derive(Clone, Debug)]
+pub struct QuerySignalOptions {
+    /// If `true` (the default), changing the value replaces the current entry in the history
+    /// stack rather than adding a new one, so the "back" button skips over it.
+    pub replace: bool,
+    /// If set, the URL is only updated once the value has stopped changing for this long.
+    /// Useful for values bound to text inputs.
+    pub debounce: Option<Duration>,
+}
+
+impl Default for QuerySignalOptions {
+    fn default() -> Self {
+        Self {
+            replace: true,
+            debounce: None,
+        }
+    }
+}
+
 /// Resolves the given path relative to the current route.
 pub fn use_resolved_path(cx: Scope, path: impl Fn() -> String + 'static) -> Memo<Option<String>> {
     let route = use_route(cx);
@@ -70,3 +221,142 @@ pub fn use_navigate(cx: Scope) -> impl Fn(&str, NavigateOptions) -> Result<(), N
     let router = use_router(cx);
     move |to, options| Rc::clone(&router.inner).navigate_from_route(to, &options)
 }
+
+/// Returns the routes that match the current location, from the outermost to the innermost,
+/// along with their params and any `meta` data attached to them. This can be used,
+/// for example, to build breadcrumbs or to check whether each route may be shown.
+///
+/// This can be used anywhere inside the [Router](crate::Router), even outside the [Routes](crate::Routes).
+/// However, the matches are only known once the [Routes](crate::Routes) have been created, so
+/// anything that reads them before that sees an empty list. In the browser this is updated
+/// as soon as the routes are created, but when rendering on the server, only views rendered
+/// after the `<Routes/>` include the matches.
+pub fn use_matches(cx: Scope) -> Memo<Vec<MatchedRoute>> {
+    let matches = use_router(cx).inner.matches;
+    create_memo(cx, move |_| {
+        matches.with(|matches| matches.iter().map(MatchedRoute::from).collect())
+    })
+}
+
+/// A route that matches the current location, as returned by [use_matches].
+#[derive(Clone)]
+pub struct MatchedRoute {
+    /// The portion of the URL path matched by this route.
+    pub path: String,
+    /// The full path pattern of the route, like `/users/:id`.
+    pub pattern: String,
+    /// The params matched by this route.
+    pub params: ParamsMap,
+    meta: Option<Rc<dyn Any>>,
+}
+
+impl MatchedRoute {
+    /// The `meta` data attached to the route, if it exists and is of type `T`.
+    pub fn meta<T: 'static>(&self) -> Option<&T> {
+        self.meta.as_ref()?.downcast_ref()
+    }
+}
+
+impl From<&RouteMatch> for MatchedRoute {
+    fn from(route_match: &RouteMatch) -> Self {
+        Self {
+            path: route_match.path_match.path.clone(),
+            pattern: route_match.route.pattern.clone(),
+            params: route_match.path_match.params.clone(),
+            meta: route_match.route.key.meta.clone(),
+        }
+    }
+}
+
+impl PartialEq for MatchedRoute {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.pattern == other.pattern
+            && self.params == other.params
+            && match (&self.meta, &other.meta) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl std::fmt::Debug for MatchedRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MatchedRoute")
+            .field("path", &self.path)
+            .field("pattern", &self.pattern)
+            .field("params", &self.params)
+            .field("meta", &self.meta.is_some())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use std::cell::RefCell;
+
+    use leptos::{provide_context, run_scope};
+
+    use super::*;
+    use crate::{RouterIntegrationContext, ServerIntegration};
+
+    #[test]
+    fn query_signal_debounces_url_updates() {
+        run_scope(|cx| {
+            provide_context(
+                cx,
+                RouterIntegrationContext::new(ServerIntegration {
+                    path: "http://leptos.dev/search?page=1&q=a+b".to_string(),
+                }),
+            );
+            let router = RouterContext::new(cx, None, None);
+            provide_context(cx, router.clone());
+            let location = router.inner.location.clone();
+            let navigate = use_navigate(cx);
+
+            // an unchanged value doesn't touch the URL, even if it would be written differently
+            let (q, set_q) = use_query_signal::<String>(cx, "q");
+            set_q(Some("a b".to_string()));
+            assert_eq!(q(), Some("a b".to_string()));
+            assert_eq!(location.search.get(), "page=1&q=a+b");
+
+            // debounced updates are queued up here and run by hand
+            let timers = Rc::new(RefCell::new(Vec::<Box<dyn FnOnce()>>::new()));
+            let options = QuerySignalOptions {
+                replace: true,
+                debounce: Some(Duration::from_millis(100)),
+            };
+            let (page, set_page) = query_signal::<usize>(cx, "page", options, {
+                let timers = Rc::clone(&timers);
+                move |f, _| timers.borrow_mut().push(f)
+            });
+            assert_eq!(page(), Some(1));
+
+            // the value changes right away, but only the last change reaches the URL
+            set_page(Some(2));
+            set_page(Some(3));
+            assert_eq!(page(), Some(3));
+            assert_eq!(location.search.get(), "page=1&q=a+b");
+            let mut queued = timers.borrow_mut().drain(..).collect::<Vec<_>>();
+            assert_eq!(queued.len(), 2);
+            (queued.remove(0))();
+            assert_eq!(location.search.get(), "page=1&q=a+b");
+
+            // going back while the update is pending doesn't overwrite the new value...
+            navigate("/search?page=5", Default::default()).unwrap();
+            assert_eq!(page(), Some(3));
+
+            // ...which is written to the URL once the timer fires
+            (queued.remove(0))();
+            assert_eq!(page(), Some(3));
+            assert_eq!(location.query.with(|q| q.get("page").cloned()), Some("3".to_string()));
+            assert_eq!(location.search.get(), "page=3");
+
+            // with nothing pending, the URL is the source of truth again
+            navigate("/search?page=7", Default::default()).unwrap();
+            assert_eq!(page(), Some(7));
+            assert_eq!(q(), None);
+        });
+    }
+}
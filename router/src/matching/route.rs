@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{any::Any, rc::Rc};
 
 use leptos::leptos_dom::Child;
 use leptos::*;
@@ -8,6 +8,8 @@ pub struct RouteDefinition {
     pub path: &'static str,
     pub children: Vec<RouteDefinition>,
     pub element: Rc<dyn Fn(Scope) -> Child>,
+    pub keep_alive: bool,
+    pub meta: Option<Rc<dyn Any>>,
 }
 
 impl std::fmt::Debug for RouteDefinition {
@@ -15,13 +17,17 @@ impl std::fmt::Debug for RouteDefinition {
         f.debug_struct("RouteDefinition")
             .field("path", &self.path)
             .field("children", &self.children)
+            .field("keep_alive", &self.keep_alive)
+            .field("meta", &self.meta.is_some())
             .finish()
     }
 }
 
 impl PartialEq for RouteDefinition {
     fn eq(&self, other: &Self) -> bool {
-        self.path == other.path && self.children == other.children
+        self.path == other.path
+            && self.children == other.children
+            && self.keep_alive == other.keep_alive
     }
 }
 
@@ -31,6 +37,8 @@ impl Default for RouteDefinition {
             path: Default::default(),
             children: Default::default(),
             element: Rc::new(|_| Child::Null),
+            keep_alive: false,
+            meta: None,
         }
     }
 }
@@ -0,0 +1,402 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn link_active_and_inactive_classes() {
+    use leptos::*;
+    use leptos_router::*;
+
+    let rendered = run_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/about".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        view! {
+            cx,
+            <div>
+                <Router>
+                    <nav>
+                        <A href="/about" active_class="active" inactive_class="inactive">"About"</A>
+                        <A href="/contact" active_class="active" inactive_class="inactive">"Contact"</A>
+                    </nav>
+                </Router>
+            </div>
+        }
+    });
+
+    assert_eq!(
+        rendered,
+        r#"<div data-hk="0-0"><!--#--><nav data-hk="0-2"><!--#--><a data-hk="0-4-0" class="active" href="/about" aria-current="page"><!--#-->About<!--/--></a><!--/--><!--#--><a data-hk="0-5-0" class="inactive" href="/contact" ><!--#-->Contact<!--/--></a><!--/--></nav><!--/--></div>"#
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn link_active_with_query_and_route_patterns() {
+    use leptos::*;
+    use leptos_router::*;
+
+    let rendered = run_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/files/a:b?tab=team".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        view! {
+            cx,
+            <div>
+                <Router>
+                    <nav>
+                        <A href="/files/a:b" exact=true active_class="active">"Literal"</A>
+                        <A href="/files/a:b/" exact=true active_class="active">"Trailing slash"</A>
+                        <A href="/files/:name/edit" active_class="active">"Longer pattern"</A>
+                        <A href="/files/:name" active_class="active">"Pattern"</A>
+                        <A href="/files/*" active_class="active">"Wildcard"</A>
+                        <A href="/files/a:b?tab=history" active_class="active">"Any query"</A>
+                        <A href="/files/a:b?tab=team" match_query=true active_class="active">"Same query"</A>
+                        <A href="/files/a:b?tab=history" match_query=true active_class="active">"Other query"</A>
+                    </nav>
+                </Router>
+            </div>
+        }
+    });
+
+    // the text of each link marked as active
+    let active = rendered
+        .split("<a ")
+        .skip(1)
+        .filter(|link| link.contains(r#"class="active""#))
+        .map(|link| link.split("-->").nth(1).unwrap().split("<!--").next().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        active,
+        vec![
+            "Literal",
+            "Trailing slash",
+            "Pattern",
+            "Wildcard",
+            "Any query",
+            "Same query"
+        ]
+    );
+}
+
+#[cfg(feature = "ssr")]
+use router_handle::*;
+
+// Hands the router's `prefetch` and `navigate` to a test, to call after rendering.
+#[cfg(feature = "ssr")]
+mod router_handle {
+    use leptos::*;
+    use leptos_router::*;
+    use std::cell::RefCell;
+
+    thread_local! {
+        #[allow(clippy::type_complexity)]
+        static HANDLE: RefCell<Option<(RouterContext, Box<dyn Fn(&str)>)>> = const { RefCell::new(None) };
+    }
+
+    #[component]
+    pub fn RouterHandle(cx: Scope) -> Element {
+        let navigate = use_navigate(cx);
+        HANDLE.with(|handle| {
+            *handle.borrow_mut() = Some((
+                use_router(cx),
+                Box::new(move |to| navigate(to, Default::default()).unwrap()),
+            ))
+        });
+        view! { cx, <span/> }
+    }
+
+    pub fn take_router() -> (RouterContext, Box<dyn Fn(&str)>) {
+        HANDLE.with(|handle| handle.borrow_mut().take().unwrap())
+    }
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn prefetched_routes_are_adopted_on_navigation() {
+    use leptos::*;
+    use leptos_router::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static ABOUT_RENDERS: Cell<usize> = const { Cell::new(0) };
+        static CONTACT_RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    run_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        _ = view! {
+            cx,
+            <div>
+                <Router>
+                    <main>
+                        <Routes>
+                            <Route path="" element=|cx| view! { cx, <p>"Home"</p> }/>
+                            <Route path="about" element=|cx| {
+                                ABOUT_RENDERS.with(|n| n.set(n.get() + 1));
+                                view! { cx, <p>"About"</p> }
+                            }/>
+                            <Route path="contact" element=|cx| {
+                                CONTACT_RENDERS.with(|n| n.set(n.get() + 1));
+                                view! { cx, <p>"Contact"</p> }
+                            }/>
+                        </Routes>
+                    </main>
+                    <RouterHandle/>
+                </Router>
+            </div>
+        };
+        let (router, navigate) = take_router();
+
+        // prefetching renders the route ahead of time, but only once
+        router.prefetch("/about");
+        router.prefetch("/about?tab=team");
+        assert_eq!(ABOUT_RENDERS.with(Cell::get), 1);
+
+        // navigating there adopts the prefetched route instead of rendering it again
+        navigate("/about");
+        assert_eq!(router.pathname().get(), "/about");
+        assert_eq!(ABOUT_RENDERS.with(Cell::get), 1);
+
+        // a route that wasn't prefetched is rendered when it's navigated to
+        navigate("/contact");
+        assert_eq!(CONTACT_RENDERS.with(Cell::get), 1);
+
+        // once a prefetched route has been adopted, it's gone from the cache
+        navigate("/about");
+        assert_eq!(ABOUT_RENDERS.with(Cell::get), 2);
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn nested_route_scopes_are_disposed_when_leaving_them() {
+    use leptos::*;
+    use leptos_router::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static USER_CLEANUPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    run_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/users/1".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        _ = view! {
+            cx,
+            <div>
+                <Router>
+                    <main>
+                        <Routes>
+                            <Route path="" element=|cx| view! { cx, <p>"Home"</p> }/>
+                            <Route path="users" element=|cx| view! { cx, <section><Outlet/></section> }>
+                                <Route path=":id" element=|cx| {
+                                    on_cleanup(cx, || USER_CLEANUPS.with(|n| n.set(n.get() + 1)));
+                                    view! { cx, <p>"User"</p> }
+                                }/>
+                                <Route path="" element=|cx| view! { cx, <p>"No user"</p> }/>
+                            </Route>
+                        </Routes>
+                    </main>
+                    <RouterHandle/>
+                </Router>
+            </div>
+        };
+        let (_, navigate) = take_router();
+        assert_eq!(USER_CLEANUPS.with(Cell::get), 0);
+
+        // navigating up to the parent route replaces the nested route, disposing of its scope
+        navigate("/users");
+        assert_eq!(USER_CLEANUPS.with(Cell::get), 1);
+
+        // leaving the parent route entirely disposes of the nested routes that are left over
+        navigate("/users/2");
+        navigate("/");
+        assert_eq!(USER_CLEANUPS.with(Cell::get), 2);
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn kept_alive_routes_are_shown_again() {
+    use leptos::*;
+    use leptos_router::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static ABOUT_RENDERS: Cell<usize> = const { Cell::new(0) };
+        static ABOUT_CLEANUPS: Cell<usize> = const { Cell::new(0) };
+        static CONTACT_CLEANUPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    let disposer = create_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/about".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        _ = view! {
+            cx,
+            <div>
+                <Router>
+                    <main>
+                        <Routes>
+                            <Route path="" element=|cx| view! { cx, <p>"Home"</p> }/>
+                            <Route path="about" keep_alive=true element=|cx| {
+                                ABOUT_RENDERS.with(|n| n.set(n.get() + 1));
+                                on_cleanup(cx, || ABOUT_CLEANUPS.with(|n| n.set(n.get() + 1)));
+                                view! { cx, <p>"About"</p> }
+                            }/>
+                            <Route path="contact" element=|cx| {
+                                on_cleanup(cx, || CONTACT_CLEANUPS.with(|n| n.set(n.get() + 1)));
+                                view! { cx, <p>"Contact"</p> }
+                            }/>
+                        </Routes>
+                    </main>
+                    <RouterHandle/>
+                </Router>
+            </div>
+        };
+        let (_, navigate) = take_router();
+        assert_eq!(ABOUT_RENDERS.with(Cell::get), 1);
+
+        // the kept-alive route survives navigating away, unlike an ordinary route
+        navigate("/contact");
+        navigate("/");
+        assert_eq!(ABOUT_CLEANUPS.with(Cell::get), 0);
+        assert_eq!(CONTACT_CLEANUPS.with(Cell::get), 1);
+
+        // and it's shown again, rather than rendered again, when the user returns
+        navigate("/about");
+        assert_eq!(ABOUT_RENDERS.with(Cell::get), 1);
+        navigate("/");
+        navigate("/about");
+        assert_eq!(ABOUT_RENDERS.with(Cell::get), 1);
+        navigate("/");
+        assert_eq!(ABOUT_CLEANUPS.with(Cell::get), 0);
+    });
+
+    // kept-alive routes are disposed of along with the router
+    disposer.dispose();
+    assert_eq!(ABOUT_CLEANUPS.with(Cell::get), 1);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn ordinary_routes_are_rendered_again() {
+    use leptos::*;
+    use leptos_router::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static USER_RENDERS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    run_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/users/1".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        _ = view! {
+            cx,
+            <div>
+                <Router>
+                    <main>
+                        <Routes>
+                            <Route path="" element=|cx| view! { cx, <p>"Home"</p> }/>
+                            <Route path="users" element=|cx| view! { cx, <section><Outlet/></section> }>
+                                <Route path=":id" element=|cx| {
+                                    USER_RENDERS.with(|n| n.set(n.get() + 1));
+                                    view! { cx, <p>"User"</p> }
+                                }/>
+                            </Route>
+                        </Routes>
+                    </main>
+                    <RouterHandle/>
+                </Router>
+            </div>
+        };
+        let (_, navigate) = take_router();
+        assert_eq!(USER_RENDERS.with(Cell::get), 1);
+
+        // without keep_alive, the route doesn't hold on to the view it rendered before
+        navigate("/users/2");
+        assert_eq!(USER_RENDERS.with(Cell::get), 2);
+        navigate("/");
+        navigate("/users/1");
+        assert_eq!(USER_RENDERS.with(Cell::get), 3);
+    });
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn matches_are_available_outside_the_routes() {
+    use leptos::*;
+    use leptos_router::*;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static RENDERED: RefCell<Vec<Vec<String>>> = const { RefCell::new(Vec::new()) };
+        static MATCHES: RefCell<Vec<Memo<Vec<MatchedRoute>>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Breadcrumbs(cx: Scope) -> Element {
+        let matches = use_matches(cx);
+        RENDERED.with(|rendered| rendered.borrow_mut().push(patterns(matches)));
+        MATCHES.with(|all| all.borrow_mut().push(matches));
+        view! { cx, <nav/> }
+    }
+
+    fn patterns(matches: Memo<Vec<MatchedRoute>>) -> Vec<String> {
+        matches.with(|matches| matches.iter().map(|m| m.pattern.clone()).collect())
+    }
+
+    run_scope(|cx| {
+        let integration = ServerIntegration {
+            path: "http://leptos.dev/users/1".to_string(),
+        };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+
+        _ = view! {
+            cx,
+            <div>
+                <Router>
+                    <Breadcrumbs/>
+                    <main>
+                        <Routes>
+                            <Route path="" element=|cx| view! { cx, <p>"Home"</p> }/>
+                            <Route path="users" element=|cx| view! { cx, <section><Outlet/></section> }>
+                                <Route path=":id" element=|cx| view! { cx, <p>"User"</p> }/>
+                            </Route>
+                        </Routes>
+                    </main>
+                    <Breadcrumbs/>
+                    <RouterHandle/>
+                </Router>
+            </div>
+        };
+        let (_, navigate) = take_router();
+
+        // on the server, only views rendered after the <Routes/> see the matches...
+        let user = vec!["/users".to_string(), "/users/:id".to_string()];
+        assert_eq!(RENDERED.with(|r| r.borrow().clone()), vec![vec![], user.clone()]);
+
+        // ...but they're there for everyone once the routes have been created
+        let [before, after] = MATCHES.with(|m| <[_; 2]>::try_from(m.borrow().clone()).unwrap());
+        assert_eq!(patterns(before), user);
+        assert_eq!(patterns(after), user);
+
+        navigate("/");
+        assert_eq!(patterns(before), vec!["".to_string()]);
+    });
+}